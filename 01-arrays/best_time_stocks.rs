@@ -1,6 +1,14 @@
+//! title: Best Time to Buy and Sell Stock
+//! link: https://leetcode.com/problems/best-time-to-buy-and-sell-stock/
+//! difficulty: Easy
+//! description: Pick one day to buy and a later day to sell to maximize profit from `prices`.
+//! time: O(n)
+//! space: O(1)
+
 struct Solution;
 
 impl Solution {
+    #[allow(clippy::needless_range_loop)]
     pub fn max_profit(prices: Vec<i32>) -> i32 {
         if prices.is_empty() {
             return 0;
@@ -1,24 +1,16 @@
+//! title: Contains Duplicate
+//! link: https://leetcode.com/problems/contains-duplicate/
+//! difficulty: Easy
+//! description: Return true if any value appears at least twice in `nums`.
+//! time: O(n)
+//! space: O(n)
+
+use dsa::modules::harness::Harness;
 use std::collections::HashSet;
 
 struct Solution;
 
 impl Solution {
-    // The above only works if it's sorted and the numbers are repeated
-    // one after another
-    // pub fn contains_duplicate(nums: Vec<i32>) -> bool {
-    //     let mut num = nums[0];
-    //     let mut value = false;
-
-    //     for i in 1..nums.len() {
-    //         if num == nums[i] {
-    //             value = true;
-    //         } else {
-    //             num = nums[i];
-    //         }
-    //     }
-    //     value
-    // }
-
     pub fn contains_duplicate(nums: Vec<i32>) -> bool {
         let mut seen = HashSet::new();
 
@@ -32,11 +24,43 @@ impl Solution {
     }
 }
 
+// Only works if it's sorted and the numbers are repeated one after another
+mod sorted_scan {
+    pub struct Solution;
+
+    impl Solution {
+        #[allow(clippy::needless_range_loop)]
+        pub fn contains_duplicate(nums: Vec<i32>) -> bool {
+            let mut num = nums[0];
+            let mut value = false;
+
+            for i in 1..nums.len() {
+                if num == nums[i] {
+                    value = true;
+                } else {
+                    num = nums[i];
+                }
+            }
+            value
+        }
+    }
+}
+
 fn main() {
-    println!("{}", Solution::contains_duplicate(vec![1, 2, 3, 1])); // true
-    println!("{}", Solution::contains_duplicate(vec![1, 2, 3, 4])); // false
-    println!(
-        "{}",
-        Solution::contains_duplicate(vec![1, 1, 1, 3, 3, 4, 3, 2, 4, 2])
-    ); // true
+    Harness::new("Contains Duplicate")
+        .approach("hash set", |(nums,)| Solution::contains_duplicate(nums))
+        .approach("sort + scan", |(mut nums,): (Vec<i32>,)| {
+            nums.sort_unstable();
+            sorted_scan::Solution::contains_duplicate(nums)
+        })
+        .case((vec![1, 2, 3, 1],), true)
+        .case((vec![1, 2, 3, 4],), false)
+        .case((vec![1, 1, 1, 3, 3, 4, 3, 2, 4, 2],), true)
+        .generator(|rng, n| (rng.distinct_vec(n, -1_000_000..=1_000_000),))
+        .fuzz_generator(|rng, n| (rng.vec(n, -(n as i32)..=n as i32),))
+        .constraint(|(nums,)| !nums.is_empty())
+        .oracle(|(nums,)| {
+            (0..nums.len()).any(|i| ((i + 1)..nums.len()).any(|j| nums[i] == nums[j]))
+        })
+        .run();
 }
@@ -1,6 +1,14 @@
+//! title: Maximum Subarray
+//! link: https://leetcode.com/problems/maximum-subarray/
+//! difficulty: Medium
+//! description: Find the contiguous subarray with the largest sum.
+//! time: O(n)
+//! space: O(1)
+
 struct Solution;
 
 impl Solution {
+    #[allow(clippy::needless_range_loop)]
     pub fn max_sub_array(nums: Vec<i32>) -> i32 {
         if nums.is_empty() {
             return 0;
@@ -1,3 +1,13 @@
+//! title: Find Minimum in Rotated Sorted Array
+//! link: https://leetcode.com/problems/find-minimum-in-rotated-sorted-array/
+//! difficulty: Medium
+//! description: Find the minimum element of a sorted array that was rotated an unknown number of times.
+//! time: O(log n)
+//! space: O(1)
+
+use dsa::modules::generate;
+use dsa::modules::harness::Harness;
+
 struct Solution;
 
 impl Solution {
@@ -18,40 +28,25 @@ impl Solution {
 }
 
 fn main() {
-    let test_cases = vec![
-        (vec![3, 4, 5, 1, 2], 1),
-        (vec![4, 5, 6, 7, 0, 1, 2], 0),
-        (vec![11, 13, 15, 17], 11),
-        (vec![1], 1),
-        (vec![2, 1], 1),
-        (vec![1, 2], 1),
-        (vec![5, 1, 2, 3, 4], 1),
-        (vec![2, 3, 4, 5, 1], 1),
-        (vec![1, 2, 3, 4, 5], 1),
-        (vec![3, 1, 2], 1),
-    ];
-
-    println!(
-        "Running {} Test cases for find minimum...\n",
-        test_cases.len()
-    );
-    for (i, (input, expected)) in test_cases.into_iter().enumerate() {
-        let result = Solution::find_min(input.clone());
-        let passed = result == expected;
-        let status = if passed { "✅ PASS" } else { "❌ FAILED" };
-
-        println!(
-            "Test {}: {}\n Input: {:?}\n Expected: {}\n Got: {}\n",
-            i + 1,
-            status,
-            input,
-            expected,
-            result
-        );
-
-        if !passed {
-            eprintln!("⚠️  Test {} failed!", i + 1);
-        }
-    }
-    println!("All tests completed!");
+    Harness::new("Find Minimum in Rotated Sorted Array")
+        .approach("binary search", |(nums,)| Solution::find_min(nums))
+        .case((vec![3, 4, 5, 1, 2],), 1)
+        .case((vec![4, 5, 6, 7, 0, 1, 2],), 0)
+        .case((vec![11, 13, 15, 17],), 11)
+        .case((vec![1],), 1)
+        .case((vec![2, 1],), 1)
+        .case((vec![1, 2],), 1)
+        .case((vec![5, 1, 2, 3, 4],), 1)
+        .case((vec![2, 3, 4, 5, 1],), 1)
+        .case((vec![1, 2, 3, 4, 5],), 1)
+        .case((vec![3, 1, 2],), 1)
+        .generator(|rng, n| {
+            // Enough distinct values for every size
+            let bound = 2 * n as i32 + 1;
+            (rng.rotated_sorted(n.max(1), -bound..=bound),)
+        })
+        .fuzz_generator(|rng, n| (rng.rotated_sorted(n.max(1), -20..=20),))
+        .constraint(|(nums,)| !nums.is_empty() && generate::is_rotated_sorted(nums))
+        .oracle(|(nums,)| *nums.iter().min().unwrap())
+        .run();
 }
@@ -1,19 +1,13 @@
-struct Solution;
-
-// impl Solution {
-//     pub fn plus_one(digits: Vec<i32>) -> Vec<i32> {
-//         let num_str: String = digits.iter().map(|d| d.to_string()).collect();
-
-//         let mut num: u128 = num_str.parse().unwrap();
+//! title: Plus One
+//! link: https://leetcode.com/problems/plus-one/
+//! difficulty: Easy
+//! description: Add one to a non-negative integer stored as an array of decimal digits.
+//! time: O(n)
+//! space: O(1)
 
-//         num += 1;
+use dsa::modules::harness::Harness;
 
-//         num.to_string()
-//             .chars()
-//             .map(|c| c.to_digit(10).unwrap() as i32)
-//             .collect()
-//     }
-// }
+struct Solution;
 
 impl Solution {
     pub fn plus_one(mut digits: Vec<i32>) -> Vec<i32> {
@@ -30,7 +24,38 @@ impl Solution {
     }
 }
 
+mod parse_u128 {
+    pub struct Solution;
+
+    impl Solution {
+        pub fn plus_one(digits: Vec<i32>) -> Vec<i32> {
+            let num_str: String = digits.iter().map(|d| d.to_string()).collect();
+
+            let mut num: u128 = num_str.parse().unwrap();
+
+            num += 1;
+
+            num.to_string()
+                .chars()
+                .map(|c| c.to_digit(10).unwrap() as i32)
+                .collect()
+        }
+    }
+}
+
 fn main() {
-    let digits = vec![9, 9, 9];
-    println!("{:?}", Solution::plus_one(digits));
+    Harness::new("Plus One")
+        .approach("carry", |(digits,)| Solution::plus_one(digits))
+        .approach("u128 parse", |(digits,)| {
+            parse_u128::Solution::plus_one(digits)
+        })
+        .case((vec![9, 9, 9],), vec![1, 0, 0, 0])
+        .case((vec![1, 2, 3],), vec![1, 2, 4])
+        .case((vec![4, 3, 2, 1],), vec![4, 3, 2, 2])
+        .case((vec![0],), vec![1])
+        // All nines carry through every digit
+        .generator(|_, n| (vec![9; n],))
+        // u128 holds at most 38 decimal digits
+        .bench_limit("u128 parse", 38)
+        .run();
 }
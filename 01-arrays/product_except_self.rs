@@ -1,24 +1,15 @@
+//! title: Product of Array Except Self
+//! link: https://leetcode.com/problems/product-of-array-except-self/
+//! difficulty: Medium
+//! description: Return an array where each element is the product of all other elements, without division.
+//! time: O(n)
+//! space: O(1)
+
+use dsa::modules::harness::Harness;
+
 struct Solution;
 
 impl Solution {
-    //     pub fn product_except_self(nums: Vec<i32>) -> Vec<i32> {
-    //         let n = nums.len();
-    //         let mut result = vec![1; n];
-
-    //         for i in 0..n {
-    //             let mut product = 1;
-    //             for j in 0..i {
-    //                 product *= nums[j];
-    //             }
-    //             for j in (i + 1)..n {
-    //                 product *= nums[j];
-    //             }
-    //             result[i] = product;
-    //         }
-
-    //         result
-    //     }
-    //
     pub fn product_except_self(nums: Vec<i32>) -> Vec<i32> {
         let n = nums.len();
         let mut result = vec![1; n];
@@ -38,7 +29,43 @@ impl Solution {
     }
 }
 
+mod brute_force {
+    pub struct Solution;
+
+    impl Solution {
+        #[allow(clippy::needless_range_loop)]
+        pub fn product_except_self(nums: Vec<i32>) -> Vec<i32> {
+            let n = nums.len();
+            let mut result = vec![1; n];
+
+            for i in 0..n {
+                let mut product = 1;
+                for j in 0..i {
+                    product *= nums[j];
+                }
+                for j in (i + 1)..n {
+                    product *= nums[j];
+                }
+                result[i] = product;
+            }
+
+            result
+        }
+    }
+}
+
 fn main() {
-    let result = Solution::product_except_self(vec![1, 2, 3]);
-    println!("{:?}", result);
+    Harness::new("Product of Array Except Self")
+        .approach("prefix/suffix", |(nums,)| {
+            Solution::product_except_self(nums)
+        })
+        .approach("brute force", |(nums,)| {
+            brute_force::Solution::product_except_self(nums)
+        })
+        .case((vec![1, 2, 3],), vec![6, 3, 2])
+        .case((vec![1, 2, 3, 4],), vec![24, 12, 8, 6])
+        .case((vec![-1, 1, 0, -3, 3],), vec![0, 0, 9, 0, 0])
+        .generator(|rng, n| (rng.vec(n, -1..=1),))
+        .oracle(|(nums,)| brute_force::Solution::product_except_self(nums))
+        .run();
 }
@@ -1,6 +1,14 @@
+//! title: Remove Duplicates from Sorted Array
+//! link: https://leetcode.com/problems/remove-duplicates-from-sorted-array/
+//! difficulty: Easy
+//! description: Remove duplicates in-place from a sorted array and return the number of unique elements.
+//! time: O(n)
+//! space: O(1)
+
 struct Solution;
 
 impl Solution {
+    #[allow(clippy::ptr_arg)]
     pub fn remove_duplicates(nums: &mut Vec<i32>) -> i32 {
         if nums.is_empty() {
             return 0;
@@ -1,13 +1,16 @@
+//! title: Rotate Array
+//! link: https://leetcode.com/problems/rotate-array/
+//! difficulty: Medium
+//! description: Rotate the array to the right by `k` steps in-place.
+//! time: O(n)
+//! space: O(1)
+
+use dsa::modules::harness::Harness;
+
 struct Solution;
 
 impl Solution {
-    // pub fn rotate(nums: &mut Vec<i32>, k: i32) {
-    //     for _ in 0..k {
-    //         let last = nums.pop().unwrap();
-    //         nums.insert(0, last);
-    //     }
-    // }
-
+    #[allow(clippy::ptr_arg)]
     pub fn rotate(nums: &mut Vec<i32>, k: i32) {
         if nums.is_empty() {
             return;
@@ -35,17 +38,34 @@ impl Solution {
     }
 }
 
-fn main() {
-    let tests = vec![
-        (vec![1, 2, 3, 4, 5, 6, 7], 3),
-        (vec![-1, -100, 3, 99], 2),
-        (vec![1], 10),
-        (vec![1, 2], 0),
-        (vec![1, 2, 3], 4),
-    ];
-    for (mut arr, k) in tests {
-        println!("Before= {:?}, k= {}", arr, k);
-        Solution::rotate(&mut arr, k);
-        println!("After= {:?}, k= {}", arr, k);
+mod pop_insert {
+    pub struct Solution;
+
+    impl Solution {
+        pub fn rotate(nums: &mut Vec<i32>, k: i32) {
+            for _ in 0..k {
+                let last = nums.pop().unwrap();
+                nums.insert(0, last);
+            }
+        }
     }
 }
+
+fn main() {
+    Harness::new("Rotate Array")
+        .approach("reverse", |(mut nums, k)| {
+            Solution::rotate(&mut nums, k);
+            nums
+        })
+        .approach("pop/insert", |(mut nums, k)| {
+            pop_insert::Solution::rotate(&mut nums, k);
+            nums
+        })
+        .case((vec![1, 2, 3, 4, 5, 6, 7], 3), vec![5, 6, 7, 1, 2, 3, 4])
+        .case((vec![-1, -100, 3, 99], 2), vec![3, 99, -1, -100])
+        .case((vec![1], 10), vec![1])
+        .case((vec![1, 2], 0), vec![1, 2])
+        .case((vec![1, 2, 3], 4), vec![3, 1, 2])
+        .generator(|rng, n| (rng.vec(n, -1000..=1000), rng.int(0..=n as i32)))
+        .run();
+}
@@ -1,3 +1,13 @@
+//! title: Search in Rotated Sorted Array
+//! link: https://leetcode.com/problems/search-in-rotated-sorted-array/
+//! difficulty: Medium
+//! description: Find the index of `target` in a rotated sorted array of distinct values, or -1.
+//! time: O(log n)
+//! space: O(1)
+
+use dsa::modules::generate;
+use dsa::modules::harness::Harness;
+
 struct Solution;
 
 impl Solution {
@@ -31,10 +41,29 @@ impl Solution {
 }
 
 fn main() {
-    assert_eq!(Solution::search(vec![4, 5, 6, 7, 0, 1, 2], 0), 4);
-    assert_eq!(Solution::search(vec![4, 5, 6, 7, 0, 1, 2], 3), -1);
-    assert_eq!(Solution::search(vec![1], 0), -1);
-    assert_eq!(Solution::search(vec![1], 1), 0);
-    assert_eq!(Solution::search(vec![3, 1], 1), 1);
-    println!("All tests passed!");
+    Harness::new("Search in Rotated Sorted Array")
+        .approach("binary search", |(nums, target)| {
+            Solution::search(nums, target)
+        })
+        .case((vec![4, 5, 6, 7, 0, 1, 2], 0), 4)
+        .case((vec![4, 5, 6, 7, 0, 1, 2], 3), -1)
+        .case((vec![1], 0), -1)
+        .case((vec![1], 1), 0)
+        .case((vec![3, 1], 1), 1)
+        .generator(|rng, n| {
+            // Enough distinct values for every size
+            let bound = 2 * n as i32 + 1;
+            (
+                rng.rotated_sorted(n, -bound..=bound),
+                rng.int(-bound..=bound),
+            )
+        })
+        .fuzz_generator(|rng, n| (rng.rotated_sorted(n, -20..=20), rng.int(-20..=20)))
+        .constraint(|(nums, _)| generate::is_rotated_sorted(nums))
+        .oracle(|(nums, target)| {
+            nums.iter()
+                .position(|&x| x == target)
+                .map_or(-1, |i| i as i32)
+        })
+        .run();
 }
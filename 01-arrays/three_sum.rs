@@ -1,3 +1,12 @@
+//! title: 3Sum
+//! link: https://leetcode.com/problems/3sum/
+//! difficulty: Medium
+//! description: Find all unique triplets in `nums` that sum to zero.
+//! time: O(n²)
+//! space: O(1)
+
+use dsa::modules::harness::Harness;
+
 struct Solution;
 
 impl Solution {
@@ -18,7 +27,7 @@ impl Solution {
                 break;
             }
 
-            let (mut right, mut left) = (i + 1, n - 1);
+            let (mut left, mut right) = (i + 1, n - 1);
             while left < right {
                 let sum = nums[i] + nums[left] + nums[right];
                 if sum < 0 {
@@ -44,58 +53,58 @@ impl Solution {
     }
 }
 
-fn main() {
-    // Test cases: (input, expected_output)
-    let test_cases = vec![
-        (
-            vec![-1, 0, 1, 2, -1, -4],
-            vec![vec![-1, -1, 2], vec![-1, 0, 1]],
-        ),
-        (vec![0, 1, 1], vec![]),
-        (vec![0, 0, 0], vec![vec![0, 0, 0]]),
-        (vec![], vec![]),
-        (vec![1, 2], vec![]),
-        (vec![0, 0, 0, 0], vec![vec![0, 0, 0]]),
-        (vec![-2, 0, 1, 1, 2], vec![vec![-2, 0, 2], vec![-2, 1, 1]]),
-        (vec![1, 2, -2, -1], vec![]),
-        (vec![-1, 0, 1, 0], vec![vec![-1, 0, 1]]),
-    ];
-
-    println!("Running {} test cases for 3Sum...\n", test_cases.len());
-
-    for (i, (input, expected)) in test_cases.into_iter().enumerate() {
-        let mut input_clone = input.clone();
-        input_clone.sort_unstable(); // normalize for comparison
-
-        let mut result = Solution::three_sum(input);
-        // Sort each triplet and the whole result for consistent comparison
-        for triplet in &mut result {
-            triplet.sort_unstable();
-        }
-        result.sort_unstable();
-
-        let mut expected_normalized = expected;
-        for triplet in &mut expected_normalized {
-            triplet.sort_unstable();
-        }
-        expected_normalized.sort_unstable();
+mod brute_force {
+    pub struct Solution;
 
-        let passed = result == expected_normalized;
-        let status = if passed { "✅ PASS" } else { "❌ FAIL" };
+    impl Solution {
+        pub fn three_sum(nums: Vec<i32>) -> Vec<Vec<i32>> {
+            let n = nums.len();
+            let mut result = Vec::new();
 
-        println!(
-            "Test {}: {}\n  Input: {:?}\n  Expected: {:?}\n  Got: {:?}\n",
-            i + 1,
-            status,
-            input_clone,
-            expected_normalized,
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    for k in (j + 1)..n {
+                        if nums[i] + nums[j] + nums[k] == 0 {
+                            let mut triplet = vec![nums[i], nums[j], nums[k]];
+                            triplet.sort_unstable();
+                            if !result.contains(&triplet) {
+                                result.push(triplet);
+                            }
+                        }
+                    }
+                }
+            }
             result
-        );
-
-        if !passed {
-            eprintln!("⚠️  Test {} failed!", i + 1);
         }
     }
+}
 
-    println!("All tests completed!");
+/// Sorts each triplet and the list so any output order compares equal
+fn normalize(mut triplets: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    for triplet in &mut triplets {
+        triplet.sort_unstable();
+    }
+    triplets.sort_unstable();
+    triplets
+}
+
+fn main() {
+    Harness::new("3Sum")
+        .approach("two pointers", |(nums,)| Solution::three_sum(nums))
+        .normalize(normalize)
+        .example("nums = [-1,0,1,2,-1,-4]", "[[-1,-1,2],[-1,0,1]]")
+        .example("nums = [0,1,1]", "[]")
+        .example("nums = [0,0,0]", "[[0,0,0]]")
+        .case((vec![],), vec![])
+        .case((vec![1, 2],), vec![])
+        .case((vec![0, 0, 0, 0],), vec![vec![0, 0, 0]])
+        .case(
+            (vec![-2, 0, 1, 1, 2],),
+            vec![vec![-2, 0, 2], vec![-2, 1, 1]],
+        )
+        .case((vec![1, 2, -2, -1],), vec![])
+        .case((vec![-1, 0, 1, 0],), vec![vec![-1, 0, 1]])
+        .generator(|rng, n| (rng.vec(n, -5..=5),))
+        .oracle(|(nums,)| brute_force::Solution::three_sum(nums))
+        .run();
 }
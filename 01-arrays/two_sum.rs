@@ -1,19 +1,11 @@
-// struct Solution;
-
-// impl Solution {
-//     pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
-//         for i in 0..nums.len() {
-//             for j in (i + 1)..nums.len() {
-//                 if nums[i] + nums[j] == target {
-//                     println!("{} + {} = {}", nums[i], nums[j], nums[i] + nums[j]);
-//                     return vec![i as i32, j as i32];
-//                 }
-//             }
-//         }
-//         vec![]
-//     }
-// }
+//! title: Two Sum
+//! link: https://leetcode.com/problems/two-sum/
+//! difficulty: Easy
+//! description: Return the indices of the two numbers in `nums` that add up to `target`.
+//! time: O(n)
+//! space: O(n)
 
+use dsa::modules::harness::Harness;
 use std::collections::HashMap;
 
 struct Solution;
@@ -33,7 +25,42 @@ impl Solution {
     }
 }
 
+mod brute_force {
+    pub struct Solution;
+
+    impl Solution {
+        pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
+            for i in 0..nums.len() {
+                for j in (i + 1)..nums.len() {
+                    if nums[i] + nums[j] == target {
+                        return vec![i as i32, j as i32];
+                    }
+                }
+            }
+            vec![]
+        }
+    }
+}
+
 fn main() {
-    let result = Solution::two_sum(vec![11, 15, 2, 7], 9);
-    println!("{:?}", result);
+    Harness::new("Two Sum")
+        .approach("hash map", |(nums, target)| Solution::two_sum(nums, target))
+        .approach("brute force", |(nums, target)| {
+            brute_force::Solution::two_sum(nums, target)
+        })
+        .case((vec![11, 15, 2, 7], 9), vec![2, 3])
+        .example("nums = [2,7,11,15], target = 9", "[0,1]")
+        .example("nums = [3,2,4], target = 6", "[1,2]")
+        .example("nums = [3,3], target = 6", "[0,1]")
+        .generator(|rng, n| {
+            // Multiples of four never sum to 3, so the only pair is 1 + 2 at the end
+            let mut nums: Vec<i32> = rng
+                .distinct_vec(n.saturating_sub(2), 1..=1_000_000)
+                .into_iter()
+                .map(|x| x * 4)
+                .collect();
+            nums.extend([1, 2]);
+            (nums, 3)
+        })
+        .run();
 }
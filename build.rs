@@ -0,0 +1,140 @@
+//! Checks that Cargo.toml registers every solution file, and for the
+//! `problems` feature generates `$OUT_DIR/problems.rs`: every solution file
+//! wrapped in a module, so all of them compile into the library and run
+//! in-process under `cargo test`, which enables the feature.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/modules/drift.rs"]
+mod drift;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    // Not the category directories: cargo scans those recursively, so any
+    // edit would rebuild the library. Files added or removed without a
+    // Cargo.toml update are reported by `cargo check-bins` instead.
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let problems_feature = env::var_os("CARGO_FEATURE_PROBLEMS").is_some();
+
+    let root = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+
+    let mut problems = Vec::new();
+    for entry in fs::read_dir(&root).into_iter().flatten().flatten() {
+        let dir = entry.path();
+        let is_category = dir
+            .file_name()
+            .and_then(|s| s.to_str())
+            .is_some_and(|name| name.starts_with(|c: char| c.is_ascii_digit()));
+        if !dir.is_dir() || !is_category {
+            continue;
+        }
+
+        for file in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = file.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("rs") {
+                continue;
+            }
+            if problems_feature {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+            if let Some(bin_name) = path.file_stem().and_then(|s| s.to_str()) {
+                problems.push((bin_name.to_string(), path.clone()));
+            }
+        }
+    }
+    problems.sort();
+
+    check_manifest(&root, &problems);
+    if !problems_feature {
+        return;
+    }
+
+    let mut out = String::new();
+    let mut registry = String::new();
+    let mut tests = String::new();
+
+    for (bin_name, path) in &problems {
+        let module = module_name(bin_name);
+        let source = fs::read_to_string(path).unwrap_or_default();
+
+        out.push_str(&format!(
+            "#[allow(dead_code, clippy::all)]\npub mod {} {{\n{}\n}}\n\n",
+            module,
+            expose(&source)
+        ));
+        registry.push_str(&format!("    ({:?}, {}::main),\n", bin_name, module));
+        tests.push_str(&format!(
+            "    #[test]\n    fn {}() {{\n        crate::problems::assert_passes({:?});\n    }}\n\n",
+            module, bin_name
+        ));
+    }
+
+    out.push_str(&format!(
+        "/// Every solution's `main`, by bin name\npub const ALL: &[(&str, fn())] = &[\n{}];\n\n",
+        registry
+    ));
+    out.push_str(&format!(
+        "#[cfg(test)]\nmod tests {{\n{}\n}}\n",
+        tests.trim_end()
+    ));
+
+    let target = Path::new(&out_dir).join("problems.rs");
+    fs::write(&target, out).expect("failed to write problems.rs");
+}
+
+/// Reports solution files that Cargo.toml doesn't match: a warning by
+/// default, so `cargo run` can still fix it, and a build error with the
+/// `strict-bins` feature
+fn check_manifest(root: &str, problems: &[(String, std::path::PathBuf)]) {
+    let Ok(manifest) = fs::read_to_string(Path::new(root).join("Cargo.toml")) else {
+        return;
+    };
+    let files: Vec<String> = problems
+        .iter()
+        .filter_map(|(_, path)| path.strip_prefix(root).ok())
+        .map(|path| path.to_string_lossy().replace('\\', "/"))
+        .collect();
+
+    let drift = drift::compare(&manifest, &files);
+    if drift.is_empty() {
+        return;
+    }
+
+    if env::var_os("CARGO_FEATURE_STRICT_BINS").is_some() {
+        eprintln!("❌ Cargo.toml is out of sync with the problem directories:");
+        eprint!("{}", drift.report());
+        eprintln!("💡 Run `cargo run update` to sync it");
+        std::process::exit(1);
+    }
+    for line in drift.report().lines() {
+        println!("cargo:warning=Cargo.toml out of sync: {}", line.trim());
+    }
+    println!("cargo:warning=Run `cargo run update` to sync it");
+}
+
+/// Makes the items a solution file keeps private reachable from outside
+/// its module
+fn expose(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| match line {
+            "struct Solution;" => "pub struct Solution;".to_string(),
+            _ if line.starts_with("fn main()") => format!("pub {}", line),
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Bin names that aren't valid identifiers get a prefix
+fn module_name(bin_name: &str) -> String {
+    let name = bin_name.replace('-', "_");
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("p_{}", name)
+    } else {
+        name
+    }
+}
@@ -0,0 +1,7 @@
+// practice: none
+
+// Scaffold an attempt with: cargo run practice <name>
+
+fn main() {
+    println!("💡 No practice attempt yet. Run: cargo run practice <name>");
+}
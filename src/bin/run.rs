@@ -1,4 +1,6 @@
-use dsa::modules::{discovery, fzf, runner};
+use dsa::modules::editor::{self, Jump};
+use dsa::modules::fzf::{self, Action};
+use dsa::modules::{discovery, runner};
 
 fn main() {
     let problems = discovery::discover_problems();
@@ -9,7 +11,15 @@ fn main() {
         return;
     }
 
-    if let Some(selected) = fzf::run_fzf(&problems) {
-        runner::run_problem(&selected);
+    match fzf::run_fzf(&problems) {
+        Some((selected, Action::Run)) => {
+            runner::run_problem(&selected);
+        }
+        Some((selected, Action::Edit)) => {
+            if let Err(e) = editor::open(&selected, Jump::Solution) {
+                eprintln!("{}", e);
+            }
+        }
+        None => {}
     }
 }
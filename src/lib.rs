@@ -1 +1,7 @@
+// Lets solution files compiled into the library keep their `dsa::` imports
+extern crate self as dsa;
+
 pub mod modules;
+
+#[cfg(feature = "problems")]
+pub mod problems;
@@ -1,5 +1,17 @@
 use clap::{Parser, Subcommand};
-use dsa::modules::{discovery, runner, updater};
+use dsa::modules::bench::BenchConfig;
+use dsa::modules::editor::{self, Jump};
+use dsa::modules::fuzz::FuzzConfig;
+use dsa::modules::generate::Rng;
+use dsa::modules::harness::format_elapsed;
+use dsa::modules::history::{self, EventKind};
+use dsa::modules::judge::{self, Limits};
+use dsa::modules::mock::{self, MockConfig};
+use dsa::modules::{
+    cases, diagnostics, discovery, export, import, leetcode, metadata, notes, pick, practice,
+    provider, readme, runner, stats, template, testgen, updater, watch,
+};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "leetcode")]
@@ -12,12 +24,56 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Update Cargo.toml with all discovered problems
-    Update,
+    Update {
+        /// Also regenerate the problem table in README.md
+        #[arg(long)]
+        readme: bool,
+
+        /// Also generate a `tests/<name>.rs` file per problem, one #[test] per case
+        #[arg(long)]
+        tests: bool,
+
+        /// Only report new, missing and renamed problems; exit 1 if Cargo.toml is out of sync
+        #[arg(long, conflicts_with_all = ["readme", "tests"])]
+        check: bool,
+    },
 
     /// List all available problems
     List {
         /// Optional: Filter by category (e.g., "array", "linked-list")
         category: Option<String>,
+
+        /// Also show difficulty, complexity, the first line of each problem's notes and its method signatures
+        #[arg(long, short)]
+        long: bool,
+    },
+
+    /// Create a solution file from a problem source
+    New {
+        /// Problem slug (e.g., "two-sum")
+        slug: String,
+
+        /// Category directory to put it in (e.g., "01-arrays")
+        #[arg(long)]
+        category: String,
+
+        /// Where to fetch the problem from: "catalog" (the catalog/ directory) or "mock"
+        #[arg(long, default_value = "catalog")]
+        source: String,
+    },
+
+    /// Create a solution file from a pasted `impl Solution` stub and its examples
+    Import {
+        /// File holding the snippet; reads stdin when omitted
+        file: Option<PathBuf>,
+
+        /// Category directory to put it in (e.g., "01-arrays")
+        #[arg(long)]
+        category: String,
+
+        /// Bin and file name, instead of the method's name
+        #[arg(long)]
+        name: Option<String>,
     },
 
     /// Run a specific problem by name
@@ -25,29 +81,436 @@ enum Commands {
         /// Problem name (e.g., "two_sum")
         name: String,
     },
+
+    /// Re-solve a problem from a blank practice workspace
+    Practice {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+
+        /// Run the original tests against the current attempt
+        #[arg(long)]
+        check: bool,
+
+        /// After a passing check, diff the attempt against the stored solution
+        #[arg(long, requires = "check")]
+        diff: bool,
+
+        /// Overwrite the attempt already in the practice workspace
+        #[arg(long)]
+        reset: bool,
+    },
+
+    /// Show progress statistics over all problems and history
+    Stats,
+
+    /// Regenerate the problem table in README.md
+    Readme,
+
+    /// Benchmark every approach of a problem across input sizes
+    Bench {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+
+        /// Input sizes, comma separated (e.g., "1000,2000,4000")
+        #[arg(long, value_delimiter = ',')]
+        sizes: Vec<usize>,
+
+        /// Untimed runs before measuring each size
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs per size
+        #[arg(long, default_value_t = 10)]
+        reps: usize,
+
+        /// Also write the samples to a CSV file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+
+    /// Build every problem once and run them all in parallel
+    Test {
+        /// Optional: Filter by category (e.g., "array")
+        category: Option<String>,
+
+        /// Show the output of passing problems too
+        #[arg(long)]
+        verbose: bool,
+    },
+
+    /// Re-run a problem, or a whole category, whenever its files change
+    Watch {
+        /// Problem name (e.g., "three_sum") or category (e.g., "arrays")
+        name: String,
+    },
+
+    /// Open a problem in $VISUAL or $EDITOR at its `impl Solution` block
+    Edit {
+        /// Problem name (e.g., "three_sum")
+        name: String,
+
+        /// Jump to the first failing case or compile error instead
+        #[arg(long)]
+        failing: bool,
+    },
+
+    /// Pick a random problem
+    Random {
+        /// Only from categories matching this (e.g., "arrays")
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Only this difficulty (easy, medium, hard)
+        #[arg(long)]
+        difficulty: Option<String>,
+
+        /// Skip problems already solved according to the history
+        #[arg(long)]
+        unsolved: bool,
+
+        #[command(flatten)]
+        then: Then,
+    },
+
+    /// Today's problem: the same for everyone with the same problem set
+    Daily {
+        #[command(flatten)]
+        then: Then,
+    },
+
+    /// Timed interview: solve problems in the practice workspace against hidden cases
+    Mock {
+        /// Number of problems
+        #[arg(long, default_value_t = 3)]
+        count: usize,
+
+        /// Time limit for the whole session
+        #[arg(long, default_value_t = 45)]
+        minutes: u64,
+
+        /// Difficulty of each problem in turn, comma separated (e.g., "easy,medium,hard")
+        #[arg(long, value_delimiter = ',')]
+        mix: Vec<String>,
+
+        /// Only pick from categories matching this (e.g., "arrays")
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Overwrite the attempt already in the practice workspace
+        #[arg(long)]
+        reset: bool,
+    },
+
+    /// Run a local judge that grades submissions against hidden cases
+    Judge {
+        #[command(subcommand)]
+        action: JudgeAction,
+    },
+
+    /// Submit a solution to the local judge
+    Submit {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+
+        /// Source to submit. Defaults to the practice workspace when it holds
+        /// an attempt at this problem, else the solution file
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Address of the local judge
+        #[arg(long, default_value = judge::DEFAULT_ADDRESS)]
+        server: String,
+
+        /// Who grades it: "local" (the `judge serve` server)
+        #[arg(long, default_value = "local")]
+        judge: String,
+    },
+
+    /// Create or open a problem's notes (`<name>.md` next to the solution)
+    Note {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+
+        /// Print the notes instead of opening them
+        #[arg(long, conflicts_with = "add")]
+        show: bool,
+
+        /// Append a paragraph without opening an editor
+        #[arg(long, value_name = "TEXT")]
+        add: Option<String>,
+    },
+
+    /// Differential-test every approach against the problem's oracle
+    Fuzz {
+        /// Problem name (e.g., "three_sum")
+        name: String,
+
+        /// Number of random inputs to try
+        #[arg(long, default_value_t = 2000)]
+        iterations: usize,
+
+        /// Seed to reproduce a previous run
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Largest generated input size
+        #[arg(long, default_value_t = 8)]
+        max_len: usize,
+
+        /// Don't add the counterexample to the problem's test cases
+        #[arg(long)]
+        no_save: bool,
+    },
+
+    /// Manage a problem's test-case file
+    Case {
+        #[command(subcommand)]
+        action: CaseAction,
+    },
+
+    /// Export all solutions to another format
+    Export {
+        #[command(subcommand)]
+        format: ExportFormat,
+    },
+}
+
+/// What to do with a picked problem
+#[derive(clap::Args)]
+struct Then {
+    /// Run it right away
+    #[arg(long, conflicts_with = "edit")]
+    run: bool,
+
+    /// Open it in $VISUAL or $EDITOR
+    #[arg(long)]
+    edit: bool,
+}
+
+#[derive(Subcommand)]
+enum CaseAction {
+    /// Append a case in LeetCode notation, prompting for missing parts
+    Add {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+
+        /// Input, e.g. "nums = [2,7,11,15], target = 9"
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Expected output, e.g. "[0,1]"
+        #[arg(long)]
+        output: Option<String>,
+    },
+
+    /// Show the cases stored for a problem
+    List {
+        /// Problem name (e.g., "two_sum")
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum JudgeAction {
+    /// Listen for submissions until interrupted
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = judge::DEFAULT_ADDRESS)]
+        address: String,
+
+        /// Time limit per case in milliseconds
+        #[arg(long, default_value_t = 2000)]
+        time_limit_ms: u64,
+
+        /// Memory limit in megabytes
+        #[arg(long, default_value_t = 512)]
+        memory_mb: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum ExportFormat {
+    /// Render a static HTML site
+    Html {
+        /// Output directory (e.g., "site")
+        dir: PathBuf,
+    },
+
+    /// Write a single Markdown study guide
+    Markdown {
+        /// Output file
+        #[arg(default_value = "STUDY_GUIDE.md")]
+        output: PathBuf,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Some(Commands::Update) => {
-            handle_update();
+        Some(Commands::Update {
+            readme,
+            tests,
+            check,
+        }) => {
+            if check {
+                handle_update_check();
+            } else {
+                handle_update(readme, tests);
+            }
+        }
+        Some(Commands::List { category, long }) => {
+            handle_list(category, long);
+        }
+        Some(Commands::New {
+            slug,
+            category,
+            source,
+        }) => {
+            handle_new(slug, category, source);
         }
-        Some(Commands::List { category }) => {
-            handle_list(category);
+        Some(Commands::Import {
+            file,
+            category,
+            name,
+        }) => {
+            handle_import(file, category, name);
         }
         Some(Commands::Run { name }) => {
             handle_run(name);
         }
+        Some(Commands::Practice {
+            name,
+            check,
+            diff,
+            reset,
+        }) => {
+            handle_practice(name, check, diff, reset);
+        }
+        Some(Commands::Stats) => {
+            handle_stats();
+        }
+        Some(Commands::Readme) => {
+            handle_readme();
+        }
+        Some(Commands::Bench {
+            name,
+            sizes,
+            warmup,
+            reps,
+            csv,
+        }) => {
+            let mut config = BenchConfig {
+                warmup,
+                reps,
+                csv: csv.map(|p| p.display().to_string()),
+                ..BenchConfig::default()
+            };
+            if !sizes.is_empty() {
+                config.sizes = sizes;
+            }
+            handle_bench(name, config);
+        }
+        Some(Commands::Test { category, verbose }) => {
+            handle_test(category, verbose);
+        }
+        Some(Commands::Watch { name }) => {
+            handle_watch(name);
+        }
+        Some(Commands::Edit { name, failing }) => {
+            handle_edit(name, failing);
+        }
+        Some(Commands::Random {
+            category,
+            difficulty,
+            unsolved,
+            then,
+        }) => {
+            let filter = pick::Filter {
+                category,
+                difficulty,
+                unsolved,
+            };
+            handle_random(filter, then);
+        }
+        Some(Commands::Daily { then }) => {
+            handle_daily(then);
+        }
+        Some(Commands::Mock {
+            count,
+            minutes,
+            mix,
+            category,
+            reset,
+        }) => {
+            let mut config = MockConfig {
+                count,
+                minutes,
+                category,
+                reset,
+                ..MockConfig::default()
+            };
+            if !mix.is_empty() {
+                config.mix = mix;
+            }
+            handle_mock(config);
+        }
+        Some(Commands::Judge {
+            action:
+                JudgeAction::Serve {
+                    address,
+                    time_limit_ms,
+                    memory_mb,
+                },
+        }) => {
+            let limits = Limits {
+                time: std::time::Duration::from_millis(time_limit_ms),
+                memory_mb,
+            };
+            if let Err(e) = judge::serve(&address, &limits) {
+                eprintln!("{}", e);
+            }
+        }
+        Some(Commands::Submit {
+            name,
+            file,
+            server,
+            judge,
+        }) => {
+            handle_submit(name, file, server, judge);
+        }
+        Some(Commands::Note { name, show, add }) => {
+            handle_note(name, show, add);
+        }
+        Some(Commands::Fuzz {
+            name,
+            iterations,
+            seed,
+            max_len,
+            no_save,
+        }) => {
+            let config = FuzzConfig {
+                iterations,
+                seed: seed.unwrap_or_else(|| Rng::from_time().next_u64()),
+                max_len,
+                save: None,
+            };
+            handle_fuzz(name, config, !no_save);
+        }
+        Some(Commands::Case { action }) => {
+            handle_case(action);
+        }
+        Some(Commands::Export { format }) => {
+            handle_export(format);
+        }
         None => {
             // Default: Update Cargo.toml
-            handle_update();
+            handle_update(false, false);
         }
     }
 }
 
-fn handle_update() {
+fn handle_update(update_readme: bool, generate_tests: bool) {
     let problems = discovery::discover_problems();
 
     if problems.is_empty() {
@@ -56,6 +519,14 @@ fn handle_update() {
         return;
     }
 
+    if update_readme && let Err(e) = readme::update_readme(&problems) {
+        eprintln!("{}", e);
+    }
+
+    if generate_tests && let Err(e) = testgen::write_tests(&problems) {
+        eprintln!("{}", e);
+    }
+
     match updater::update_cargo_toml(&problems) {
         Ok(_) => {
             println!("\n🚀 Usage:");
@@ -69,22 +540,593 @@ fn handle_update() {
     }
 }
 
-fn handle_list(category: Option<String>) {
+fn handle_update_check() {
+    let problems = discovery::discover_problems();
+    match updater::check_cargo_toml(&problems) {
+        Ok(drift) if drift.is_empty() => {
+            println!("✅ Cargo.toml matches all {} problems", problems.len());
+        }
+        Ok(drift) => {
+            eprintln!("❌ Cargo.toml is out of sync with the problem directories:");
+            eprint!("{}", drift.report());
+            eprintln!("💡 Run `cargo run update` to sync it");
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn handle_list(category: Option<String>, long: bool) {
     let problems = match category {
         Some(cat) => discovery::list_by_category(&cat),
         None => discovery::discover_problems(),
     };
 
-    discovery::print_problems(&problems);
+    if !long || problems.is_empty() {
+        discovery::print_problems(&problems);
+        return;
+    }
+
+    println!("\n📚 Found {} problems:\n", problems.len());
+    for (i, problem) in problems.iter().enumerate() {
+        let meta = metadata::load(problem);
+        let notes = notes::load(problem);
+        let complexity = notes.clone().unwrap_or_default();
+
+        let mut details = vec![meta.difficulty_label().to_string()];
+        if let Some(time) = complexity.time_or(&meta) {
+            details.push(format!("{} time", time));
+        }
+        if let Some(space) = complexity.space_or(&meta) {
+            details.push(format!("{} space", space));
+        }
+        match notes.as_ref().and_then(|n| n.summary()) {
+            Some(summary) => details.push(format!("📝 {}", summary)),
+            None if notes.is_some() => details.push("📝".to_string()),
+            None => {}
+        }
+
+        println!("  {}. {}", i + 1, problem.display_name());
+        println!("     {}", details.join(" · "));
+        for signature in &problem.signatures() {
+            match signature.mutated() {
+                Some((name, _)) => println!("     fn {}  (mutates {})", signature, name),
+                None => println!("     fn {}", signature),
+            }
+        }
+    }
+    println!();
 }
 
 fn handle_run(name: String) {
     let problems = discovery::discover_problems();
 
     if let Some(problem) = problems.iter().find(|p| p.bin_name == name) {
-        runner::run_problem(problem);
+        let passed = runner::run_problem(problem);
+        if let Err(e) = history::record(&problem.bin_name, EventKind::Run, passed) {
+            eprintln!("{}", e);
+        }
+    } else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+    }
+}
+
+fn handle_practice(name: String, check: bool, diff: bool, reset: bool) {
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let result = if check {
+        practice::check(problem, diff).map(|_| ())
+    } else {
+        practice::scaffold(problem, reset)
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_stats() {
+    let problems = discovery::discover_problems();
+
+    if problems.is_empty() {
+        println!("⚠️  No LeetCode solutions found!");
+        return;
+    }
+
+    stats::print_report(&problems);
+}
+
+fn handle_readme() {
+    let problems = discovery::discover_problems();
+
+    if problems.is_empty() {
+        println!("⚠️  No LeetCode solutions found!");
+        return;
+    }
+
+    if let Err(e) = readme::update_readme(&problems) {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_export(format: ExportFormat) {
+    let problems = discovery::discover_problems();
+
+    if problems.is_empty() {
+        println!("⚠️  No LeetCode solutions found!");
+        return;
+    }
+
+    let result = match format {
+        ExportFormat::Html { dir } => export::export_html(&problems, &dir),
+        ExportFormat::Markdown { output } => export::export_markdown(&problems, &output),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_bench(name: String, mut config: BenchConfig) {
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let has_generator = std::fs::read_to_string(&problem.path)
+        .map(|source| source.contains(".generator("))
+        .unwrap_or(false);
+    if !has_generator {
+        println!("⚠️  {} has no input generator.", problem.path);
+        println!("💡 Add .generator(|rng, n| ..) to its Harness to benchmark it");
+        return;
+    }
+
+    // Complexity in the notes takes precedence over the solution header
+    let notes = notes::load(problem).unwrap_or_default();
+    config.claim = notes.time.clone().or(metadata::load(problem).time);
+    config.approach_claims = notes.approach_times;
+
+    println!(
+        "\n⏱️  Benchmarking: {} from {}\n",
+        problem.name, problem.category
+    );
+    println!("{}", "=".repeat(50));
+    bench_problem(&problem.bin_name, &config);
+}
+
+/// With the `problems` feature every solution is compiled into this
+/// binary, so the harness benchmarks in-process (build with `--release`
+/// for meaningful timings); otherwise the bin is built and run in release
+#[cfg(feature = "problems")]
+fn bench_problem(bin_name: &str, config: &BenchConfig) {
+    if let Err(e) = dsa::problems::bench(bin_name, config) {
+        eprintln!("{}", e);
+    }
+}
+
+#[cfg(not(feature = "problems"))]
+fn bench_problem(bin_name: &str, config: &BenchConfig) {
+    runner::run_bin_with(bin_name, true, &config.to_args());
+}
+
+fn handle_test(category: Option<String>, verbose: bool) {
+    let problems = match category {
+        Some(cat) => discovery::list_by_category(&cat),
+        None => discovery::discover_problems(),
+    };
+    if problems.is_empty() {
+        println!("⚠️  No problems found!");
+        return;
+    }
+
+    let bins: Vec<String> = problems.iter().map(|p| p.bin_name.clone()).collect();
+    println!("\n📦 Building {} problems...\n", bins.len());
+    let start = std::time::Instant::now();
+    let build = match runner::build_bins(&bins, false) {
+        Ok(build) => build,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("🧪 Running {} binaries...\n", build.artifacts.len());
+    let results = runner::run_artifacts(&build.artifacts, &[]);
+
+    let mut failed = 0;
+    for problem in &problems {
+        match results.iter().find(|r| r.bin_name == problem.bin_name) {
+            Some(result) => {
+                let mark = if result.passed { "✅" } else { "❌" };
+                println!(
+                    "  {} {:<30} {:>10}",
+                    mark,
+                    problem.name,
+                    format_elapsed(result.elapsed)
+                );
+                failed += usize::from(!result.passed);
+            }
+            None => {
+                println!("  🔨 {:<30} {:>10}", problem.name, "build failed");
+                failed += 1;
+            }
+        }
+    }
+
+    // Library errors break every bin, so show them once up front
+    let library_errors = build.diagnostics_for("dsa");
+    if library_errors.iter().any(|d| d.is_error()) {
+        println!("\n🔨 The dsa library failed to compile:\n");
+        print!("{}", diagnostics::render(&library_errors));
+    }
+
+    for problem in &problems {
+        let compiled = build
+            .artifacts
+            .iter()
+            .any(|a| a.bin_name == problem.bin_name);
+        let messages = build.diagnostics_for(&problem.bin_name);
+        if !compiled && !messages.is_empty() {
+            println!("\n🔨 {} ({})", problem.bin_name, problem.path);
+            print!("{}", diagnostics::render(&messages));
+        }
+    }
+
+    for result in &results {
+        if verbose || !result.passed {
+            println!("\n── {} {}", result.bin_name, "─".repeat(40));
+            println!("{}", result.output.trim_end());
+        }
+    }
+
+    println!(
+        "\n{} {}/{} passed in {}",
+        if failed == 0 { "✅" } else { "❌" },
+        problems.len() - failed,
+        problems.len(),
+        format_elapsed(start.elapsed())
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn handle_watch(name: String) {
+    if let Err(e) = watch::watch(&watch::Selection::new(&name)) {
+        eprintln!("{}", e);
+        println!("💡 Use 'cargo run list' to see available problems");
+    }
+}
+
+fn handle_edit(name: String, failing: bool) {
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let jump = if failing {
+        Jump::Failing
     } else {
+        Jump::Solution
+    };
+    if let Err(e) = editor::open(problem, jump) {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_random(filter: pick::Filter, then: Then) {
+    let problems = discovery::discover_problems();
+
+    match pick::random(&problems, &filter, &mut Rng::from_time()) {
+        Some(problem) => {
+            pick::print(&problem);
+            follow_up(&problem, then);
+        }
+        None => {
+            println!("⚠️  No problem matches those filters.");
+            println!("💡 Use 'cargo run list --long' to see categories and difficulties");
+        }
+    }
+}
+
+fn handle_daily(then: Then) {
+    let problems = discovery::discover_problems();
+    let day = history::day_of(history::now());
+
+    let Some(problem) = pick::daily(&problems, day) else {
+        println!("⚠️  No LeetCode solutions found!");
+        return;
+    };
+    println!("📅 Problem of the day, {}\n", history::format_day(day));
+    pick::print(&problem);
+    follow_up(&problem, then);
+}
+
+/// Runs or opens a problem picked by `random` or `daily`
+fn follow_up(problem: &discovery::Problem, then: Then) {
+    if then.run {
+        let passed = runner::run_problem(problem);
+        if let Err(e) = history::record(&problem.bin_name, EventKind::Run, passed) {
+            eprintln!("{}", e);
+        }
+    } else if then.edit {
+        editor::open(problem, Jump::Solution).unwrap_or_else(|e| eprintln!("{}", e));
+    } else {
+        println!("\n💡 Run it with: cargo run run {}", problem.bin_name);
+    }
+}
+
+fn handle_mock(config: MockConfig) {
+    if let Err(e) = mock::run(&config) {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_new(slug: String, category: String, source: String) {
+    let created = provider::source(&source).and_then(|source| {
+        let spec = source.fetch(&slug).map_err(|e| match source.list() {
+            Ok(slugs) => format!(
+                "{}\n💡 The {} source has: {}",
+                e,
+                source.name(),
+                slugs.join(", ")
+            ),
+            Err(_) => e,
+        })?;
+        template::create(&spec, &category).map(|path| (spec, path))
+    });
+
+    match created {
+        Ok((spec, path)) => {
+            println!("✅ Created {}", path);
+            println!("💡 Run it with: cargo run run {}", spec.bin_name());
+            if let Err(e) = history::record(&spec.bin_name(), EventKind::Start, true) {
+                eprintln!("{}", e);
+            }
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
+fn handle_import(file: Option<PathBuf>, category: String, name: Option<String>) {
+    use std::io::{IsTerminal, Read};
+
+    let text = match &file {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("❌ Failed to read {}: {}", path.display(), e)),
+        None => {
+            if std::io::stdin().is_terminal() {
+                println!("📋 Paste the `impl Solution` stub and the examples, then press Ctrl-D:");
+            }
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map(|_| text)
+                .map_err(|e| format!("❌ Failed to read stdin: {}", e))
+        }
+    };
+
+    let created = text
+        .and_then(|text| import::parse_snippet(&text, name.as_deref()))
+        .and_then(|spec| template::create(&spec, &category).map(|path| (spec, path)));
+
+    match created {
+        Ok((spec, path)) => {
+            println!(
+                "✅ Created {} with {} example(s)",
+                path,
+                spec.examples.len()
+            );
+            println!("💡 Run it with: cargo run run {}", spec.bin_name());
+            if let Err(e) = history::record(&spec.bin_name(), EventKind::Start, true) {
+                eprintln!("{}", e);
+            }
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
+fn handle_submit(name: String, file: Option<PathBuf>, server: String, judge: String) {
+    let judge = match provider::judge(&judge, &server) {
+        Ok(judge) => judge,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
         println!("❌ Problem '{}' not found.", name);
         println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let path = file.unwrap_or_else(|| {
+        if practice::current_problem().as_deref() == Some(name.as_str()) {
+            PathBuf::from(practice::PRACTICE_PATH)
+        } else {
+            PathBuf::from(&problem.path)
+        }
+    });
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("❌ Failed to read {}: {}", path.display(), e);
+            return;
+        }
+    };
+
+    println!(
+        "📤 Submitting {} for {} to the {} judge...",
+        path.display(),
+        name,
+        judge.name()
+    );
+    match judge.submit(&name, &source) {
+        Ok(judgement) => {
+            judgement.print();
+            let passed = judgement.verdict == judge::Verdict::Accepted;
+            if let Err(e) = history::record(&name, EventKind::Run, passed) {
+                eprintln!("{}", e);
+            }
+        }
+        Err(e) => eprintln!("{}", e),
     }
 }
+
+fn handle_note(name: String, show: bool, add: Option<String>) {
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let result = if show {
+        match std::fs::read_to_string(problem.sidecar_path(notes::EXTENSION)) {
+            Ok(text) => {
+                println!("{}", text.trim_end());
+                Ok(())
+            }
+            Err(_) => {
+                println!("📝 No notes for {} yet.", problem.name);
+                println!("💡 Create them with: cargo run note {}", problem.bin_name);
+                Ok(())
+            }
+        }
+    } else if let Some(text) = add {
+        notes::append(problem, &text)
+            .map(|_| println!("✅ Added to {}", problem.sidecar_path(notes::EXTENSION)))
+    } else {
+        notes::create(problem).and_then(|path| {
+            // Land below the front matter and title, on the first section
+            let line = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|text| text.lines().position(|l| l.starts_with("## ")))
+                .map_or(1, |i| i + 2);
+            editor::open_location(&editor::Location { file: path, line })
+        })
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+    }
+}
+
+fn handle_fuzz(name: String, mut config: FuzzConfig, save: bool) {
+    let problems = discovery::discover_problems();
+
+    let Some(problem) = problems.iter().find(|p| p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+
+    let source = std::fs::read_to_string(&problem.path).unwrap_or_default();
+    if !source.contains(".oracle(") || !source.contains(".generator(") {
+        println!("⚠️  {} has no oracle or input generator.", problem.path);
+        println!("💡 Add .generator(|rng, n| ..) and .oracle(|input| ..) to its Harness");
+        return;
+    }
+
+    // The counterexample becomes a regression case in the problem's cases file
+    if save {
+        config.save = Some(problem.sidecar_path(cases::EXTENSION));
+    }
+
+    println!("\n🎲 Fuzzing: {} from {}\n", problem.name, problem.category);
+    println!("{}", "=".repeat(50));
+    runner::run_bin_with(&problem.bin_name, true, &config.to_args());
+}
+
+fn handle_case(action: CaseAction) {
+    let problems = discovery::discover_problems();
+    let name = match &action {
+        CaseAction::Add { name, .. } | CaseAction::List { name } => name,
+    };
+
+    let Some(problem) = problems.iter().find(|p| &p.bin_name == name) else {
+        println!("❌ Problem '{}' not found.", name);
+        println!("💡 Use 'cargo run list' to see available problems");
+        return;
+    };
+    let path = problem.sidecar_path(cases::EXTENSION);
+
+    match action {
+        CaseAction::Add { input, output, .. } => {
+            let Some(input) = input.or_else(|| prompt("Input: ")) else {
+                return;
+            };
+            let Some(output) = output.or_else(|| prompt("Output: ")) else {
+                return;
+            };
+
+            // Types are only known to the solution, so check the notation here
+            // and let the next run report type mismatches
+            for (label, text) in [("input", &input), ("output", &output)] {
+                if let Err(e) = leetcode::parse_args(text) {
+                    println!("❌ Invalid {}: {}", label, e);
+                    return;
+                }
+            }
+
+            match cases::append(&path, &input, &output) {
+                Ok(()) => {
+                    println!("✅ Added case to {}", path);
+                    println!("💡 Run it with: cargo run run {}", problem.bin_name);
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+        }
+        CaseAction::List { .. } => {
+            let text = std::fs::read_to_string(&path).unwrap_or_default();
+            match cases::parse_file(&text) {
+                Ok(list) if list.is_empty() => {
+                    println!("📄 No cases in {}", path);
+                    println!("💡 Add one with: cargo run case add {}", problem.bin_name);
+                }
+                Ok(list) => {
+                    println!("\n📄 {} case(s) in {}:\n", list.len(), path);
+                    for (i, case) in list.iter().enumerate() {
+                        println!("  {}. Input:  {}", i + 1, case.input);
+                        println!("     Output: {}", case.output);
+                    }
+                    println!();
+                }
+                Err(e) => println!("❌ {}: {}", path, e),
+            }
+        }
+    }
+}
+
+/// Reads one trimmed line from stdin; `None` on EOF or empty input
+fn prompt(label: &str) -> Option<String> {
+    use std::io::Write;
+
+    print!("{}", label);
+    std::io::stdout().flush().ok()?;
+
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line).ok()?;
+    let line = line.trim().to_string();
+    (!line.is_empty()).then_some(line)
+}
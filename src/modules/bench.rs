@@ -0,0 +1,410 @@
+use crate::modules::generate::Rng;
+use crate::modules::harness::format_elapsed;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Seed for generated inputs so runs are comparable
+const BENCH_SEED: u64 = 42;
+
+/// An approach whose median exceeds this stops at the current size
+const SLOW_CUTOFF: Duration = Duration::from_secs(1);
+
+/// A claimed class whose fit error is within this factor of the best
+/// fit is reported as consistent
+const CLOSE_FIT: f64 = 3.0;
+
+/// Benchmark settings, parsed from the bin's command line by the harness
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    pub sizes: Vec<usize>,
+    pub warmup: usize,
+    pub reps: usize,
+    pub csv: Option<String>,
+    /// Complexity claimed for the main approach, e.g. "O(n)"
+    pub claim: Option<String>,
+    /// `(approach, complexity)` claims for other approaches, from notes
+    pub approach_claims: Vec<(String, String)>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            sizes: vec![1_000, 2_000, 4_000, 8_000, 16_000, 32_000],
+            warmup: 3,
+            reps: 10,
+            csv: None,
+            claim: None,
+            approach_claims: Vec::new(),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Returns the config when `args` contains `--bench`
+    pub fn from_args(args: &[String]) -> Option<BenchConfig> {
+        if !args.iter().any(|a| a == "--bench") {
+            return None;
+        }
+
+        let mut config = BenchConfig::default();
+        for (i, arg) in args.iter().enumerate() {
+            let value = args.get(i + 1).cloned();
+            match arg.as_str() {
+                "--sizes" => {
+                    if let Some(sizes) = value {
+                        config.sizes = sizes.split(',').filter_map(|s| s.parse().ok()).collect();
+                    }
+                }
+                "--warmup" => {
+                    if let Some(n) = value.and_then(|v| v.parse().ok()) {
+                        config.warmup = n;
+                    }
+                }
+                "--reps" => {
+                    if let Some(n) = value.and_then(|v| v.parse::<usize>().ok()) {
+                        config.reps = n.max(1);
+                    }
+                }
+                "--csv" => config.csv = value,
+                "--claim" => config.claim = value,
+                "--claim-approach" => {
+                    if let Some((name, claim)) = value.as_deref().and_then(|v| v.split_once('=')) {
+                        config
+                            .approach_claims
+                            .push((name.to_string(), claim.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(config)
+    }
+
+    /// Command-line form understood by [`BenchConfig::from_args`]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--bench".to_string(),
+            "--sizes".to_string(),
+            self.sizes
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            "--warmup".to_string(),
+            self.warmup.to_string(),
+            "--reps".to_string(),
+            self.reps.to_string(),
+        ];
+        if let Some(csv) = &self.csv {
+            args.extend(["--csv".to_string(), csv.clone()]);
+        }
+        if let Some(claim) = &self.claim {
+            args.extend(["--claim".to_string(), claim.clone()]);
+        }
+        for (name, claim) in &self.approach_claims {
+            args.extend([
+                "--claim-approach".to_string(),
+                format!("{}={}", name, claim),
+            ]);
+        }
+        args
+    }
+}
+
+/// Timing summary of one approach at one input size
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub approach: String,
+    pub size: usize,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+/// An approach to time, with the largest size it can handle
+pub struct Approach<'a, I, O> {
+    pub name: &'a str,
+    pub f: &'a dyn Fn(I) -> O,
+    pub max_size: Option<usize>,
+}
+
+/// Times every approach on generated inputs of each configured size
+pub fn run<I: Clone, O>(
+    title: &str,
+    approaches: &[Approach<I, O>],
+    generator: &dyn Fn(&mut Rng, usize) -> I,
+    config: &BenchConfig,
+) -> Vec<Sample> {
+    println!(
+        "⏱️  Benchmarking {} ({} warmup, {} reps per size)\n",
+        title, config.warmup, config.reps
+    );
+
+    let mut samples = Vec::new();
+    let mut stopped = vec![false; approaches.len()];
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for &size in &config.sizes {
+        // The silent hook is installed, so a generator panic is reported here
+        let generated = panic::catch_unwind(AssertUnwindSafe(|| {
+            generator(&mut Rng::new(BENCH_SEED ^ size as u64), size)
+        }));
+        let Ok(input) = generated else {
+            println!("💥 The generator panicked at n = {}, stopping", size);
+            break;
+        };
+
+        for (a, &Approach { name, f, max_size }) in approaches.iter().enumerate() {
+            if stopped[a] {
+                continue;
+            }
+            if let Some(max) = max_size.filter(|&max| size > max) {
+                println!(
+                    "⏭️  {} is limited to n ≤ {}, skipping larger sizes",
+                    name, max
+                );
+                stopped[a] = true;
+                continue;
+            }
+
+            // An approach that panics (e.g. overflow at large n) drops out
+            let timed = panic::catch_unwind(AssertUnwindSafe(|| {
+                for _ in 0..config.warmup {
+                    std::hint::black_box(f(input.clone()));
+                }
+
+                let mut times: Vec<Duration> = (0..config.reps)
+                    .map(|_| {
+                        let input = input.clone();
+                        let start = Instant::now();
+                        std::hint::black_box(f(input));
+                        start.elapsed()
+                    })
+                    .collect();
+                times.sort_unstable();
+                times
+            }));
+
+            let Ok(times) = timed else {
+                println!(
+                    "💥 {} panicked at n = {}, skipping larger sizes",
+                    name, size
+                );
+                stopped[a] = true;
+                continue;
+            };
+
+            let sample = Sample {
+                approach: name.to_string(),
+                size,
+                median: times[times.len() / 2],
+                p95: times[((times.len() * 95).div_ceil(100)).saturating_sub(1)],
+            };
+            stopped[a] = sample.median > SLOW_CUTOFF;
+            samples.push(sample);
+        }
+    }
+
+    panic::set_hook(hook);
+    samples
+}
+
+/// Prints the results table, fitted complexity classes and optional CSV
+pub fn report(samples: &[Sample], approaches: &[&str], config: &BenchConfig) {
+    println!(
+        "{:<18} {:>8} {:>12} {:>12}",
+        "Approach", "n", "median", "p95"
+    );
+    for sample in samples {
+        println!(
+            "{:<18} {:>8} {:>12} {:>12}",
+            sample.approach,
+            sample.size,
+            format_elapsed(sample.median),
+            format_elapsed(sample.p95)
+        );
+    }
+
+    println!("\n📈 Empirical complexity:");
+    for (i, name) in approaches.iter().enumerate() {
+        let points: Vec<(f64, f64)> = samples
+            .iter()
+            .filter(|s| s.approach == *name)
+            .map(|s| (s.size as f64, s.median.as_secs_f64()))
+            .collect();
+
+        let fits = fit_complexity(&points);
+        let Some(&(class, _)) = fits.first() else {
+            println!("   {:<18} not enough sizes to fit", name);
+            continue;
+        };
+
+        // The plain claim describes the main (first) approach; notes can
+        // claim others by name. Neighbouring classes like n and n log n are
+        // hard to separate over a few doublings, so a claim that fits almost
+        // as well is accepted.
+        let claim = config
+            .approach_claims
+            .iter()
+            .find(|(approach, _)| approach == name)
+            .map(|(_, claim)| claim)
+            .or(config.claim.as_ref().filter(|_| i == 0));
+        let verdict = verdict(&fits, claim.map(String::as_str));
+        println!(
+            "{}",
+            format!("   {:<18} ~{:<12} {}", name, class, verdict).trim_end()
+        );
+    }
+
+    if let Some(path) = &config.csv {
+        match write_csv(path, samples) {
+            Ok(()) => println!("\n💾 Wrote {}", path),
+            Err(e) => eprintln!("\n❌ Failed to write {}: {}", path, e),
+        }
+    }
+}
+
+fn write_csv(path: &str, samples: &[Sample]) -> std::io::Result<()> {
+    let mut out = String::from("approach,size,median_ns,p95_ns\n");
+    for sample in samples {
+        out.push_str(&format!(
+            "{},{},{},{}\n",
+            sample.approach,
+            sample.size,
+            sample.median.as_nanos(),
+            sample.p95.as_nanos()
+        ));
+    }
+    fs::write(path, out)
+}
+
+/// Compares a claimed class against the ranked fits, best first
+fn verdict(fits: &[(&str, f64)], claim: Option<&str>) -> String {
+    let Some(claim) = claim else {
+        return String::new();
+    };
+    let best_error = fits.first().map_or(0.0, |&(_, error)| error);
+    let claimed_error = fits
+        .iter()
+        .find(|(c, _)| normalize_class(c) == normalize_class(claim))
+        .map(|&(_, error)| error);
+    match claimed_error {
+        Some(error) if error <= best_error => format!("✅ matches claimed {}", claim),
+        Some(error) if error <= best_error * CLOSE_FIT => {
+            format!("✅ consistent with claimed {}", claim)
+        }
+        _ => format!("⚠️  claimed {}", claim),
+    }
+}
+
+type Growth = fn(f64) -> f64;
+
+/// Candidate growth functions, from slowest to fastest growing
+const CLASSES: &[(&str, Growth)] = &[
+    ("O(1)", |_| 1.0),
+    ("O(log n)", |n| n.ln()),
+    ("O(n)", |n| n),
+    ("O(n log n)", |n| n * n.ln()),
+    ("O(n²)", |n| n * n),
+    ("O(n³)", |n| n * n * n),
+];
+
+/// Ranks the classes `c·f(n)` by relative squared error over
+/// `(n, seconds)` points, best first; needs at least three sizes
+pub fn fit_complexity(points: &[(f64, f64)]) -> Vec<(&'static str, f64)> {
+    if points.len() < 3 || points.iter().any(|&(_, t)| t <= 0.0) {
+        return Vec::new();
+    }
+
+    let mut fits: Vec<(&'static str, f64)> = CLASSES
+        .iter()
+        .map(|&(name, f)| {
+            // Relative least squares: minimize Σ(1 - c·f(n)/t)²
+            let num: f64 = points.iter().map(|&(n, t)| f(n) / t).sum();
+            let den: f64 = points.iter().map(|&(n, t)| (f(n) / t).powi(2)).sum();
+            let c = num / den;
+            let error: f64 = points
+                .iter()
+                .map(|&(n, t)| (1.0 - c * f(n) / t).powi(2))
+                .sum();
+            (name, error)
+        })
+        .collect();
+    fits.sort_by(|a, b| a.1.total_cmp(&b.1));
+    fits
+}
+
+/// "O(n^2)" and "O( n² )" compare equal
+fn normalize_class(class: &str) -> String {
+    class
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace("^2", "²")
+        .replace("^3", "³")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZES: [f64; 5] = [1_000.0, 2_000.0, 4_000.0, 8_000.0, 16_000.0];
+
+    fn timings(f: Growth) -> Vec<(f64, f64)> {
+        SIZES.iter().map(|&n| (n, 1e-9 * f(n))).collect()
+    }
+
+    #[test]
+    fn fits_synthetic_timings_to_their_class() {
+        let table: [(&str, Growth); 4] = [
+            ("O(1)", |_| 1e3),
+            ("O(n)", |n| n),
+            ("O(n log n)", |n| n * n.ln()),
+            ("O(n²)", |n| n * n),
+        ];
+        for (class, f) in table {
+            let fits = fit_complexity(&timings(f));
+            assert_eq!(fits.len(), CLASSES.len());
+            assert_eq!(fits[0].0, class, "{:?}", fits);
+            assert!(fits[0].1 < 1e-12, "{:?}", fits);
+        }
+    }
+
+    #[test]
+    fn fitting_needs_three_positive_timings() {
+        assert!(fit_complexity(&timings(|n| n)[..2]).is_empty());
+        assert!(fit_complexity(&[(1.0, 1.0), (2.0, 0.0), (4.0, 4.0)]).is_empty());
+    }
+
+    #[test]
+    fn claims_within_close_fit_are_consistent() {
+        // Between n and n log n, closer to n: n log n fits a little worse
+        let between = timings(|n| n * n.ln().powf(0.4));
+        let fits = fit_complexity(&between);
+        assert_eq!(fits[0].0, "O(n)");
+
+        assert_eq!(verdict(&fits, Some("O(n)")), "✅ matches claimed O(n)");
+        assert_eq!(
+            verdict(&fits, Some("O(n log n)")),
+            "✅ consistent with claimed O(n log n)"
+        );
+        assert_eq!(verdict(&fits, Some("O(n^2)")), "⚠️  claimed O(n^2)");
+        assert_eq!(verdict(&fits, Some("O(2^n)")), "⚠️  claimed O(2^n)");
+        assert_eq!(verdict(&fits, None), "");
+    }
+
+    #[test]
+    fn normalizes_class_spelling() {
+        for (a, b) in [
+            ("O(n^2)", "O( n² )"),
+            ("O(N log N)", "o(nlogn)"),
+            ("O(n^3)", "O(n³)"),
+        ] {
+            assert_eq!(normalize_class(a), normalize_class(b));
+        }
+        assert_ne!(normalize_class("O(n)"), normalize_class("O(n²)"));
+    }
+}
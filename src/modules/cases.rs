@@ -0,0 +1,222 @@
+use crate::modules::leetcode::{self, FromLeetCode};
+use crate::modules::{discovery, practice};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Extension of the test-case file stored next to each solution
+pub const EXTENSION: &str = "cases";
+
+/// Written at the top of a newly created cases file
+const FILE_HEADER: &str = "# Test cases in LeetCode notation, loaded by the harness on every run.\n\
+                           # Each case is an `Input:` line followed by an `Output:` line.\n";
+
+/// One case as written in the file, before parsing into typed values
+#[derive(Debug, Clone)]
+pub struct RawCase {
+    /// 1-based line of the `Input:` line, for error messages
+    pub line: usize,
+    pub input: String,
+    pub output: String,
+}
+
+/// Reads `Input:` / `Output:` pairs, as copied from a problem statement.
+/// `#` comments and `Explanation:` lines are skipped. A value wrapped over
+/// several lines continues until the next blank or labelled line.
+pub fn parse_file(text: &str) -> Result<Vec<RawCase>, String> {
+    let mut cases: Vec<RawCase> = Vec::new();
+    let mut pending: Option<(usize, String)> = None;
+    // Whether the previous line was a value that the next one may continue
+    let mut open = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("Explanation:") {
+            open = false;
+            continue;
+        }
+
+        if let Some(input) = line.strip_prefix("Input:") {
+            if let Some((start, _)) = pending {
+                return Err(format!("line {}: Input without an Output", start));
+            }
+            pending = Some((i + 1, input.trim().to_string()));
+        } else if let Some(output) = line.strip_prefix("Output:") {
+            let Some((start, input)) = pending.take() else {
+                return Err(format!("line {}: Output without an Input", i + 1));
+            };
+            cases.push(RawCase {
+                line: start,
+                input,
+                output: output.trim().to_string(),
+            });
+        } else if open
+            && let Some(value) = match &mut pending {
+                Some((_, input)) => Some(input),
+                None => cases.last_mut().map(|case| &mut case.output),
+            }
+        {
+            value.push(' ');
+            value.push_str(line);
+            continue;
+        } else {
+            return Err(format!("line {}: expected 'Input:' or 'Output:'", i + 1));
+        }
+        open = true;
+    }
+
+    match pending {
+        Some((start, _)) => Err(format!("line {}: Input without an Output", start)),
+        None => Ok(cases),
+    }
+}
+
+/// Loads and parses every case in `path`; a missing file has no cases
+pub fn load<I: FromLeetCode, O: FromLeetCode>(path: &str) -> Result<Vec<(I, O)>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(_) if !Path::new(path).exists() => return Ok(Vec::new()),
+        Err(e) => return Err(format!("❌ Failed to read {}: {}", path, e)),
+    };
+
+    parse_file(&text)
+        .map_err(|e| format!("❌ {}: {}", path, e))?
+        .into_iter()
+        .map(|case| {
+            let input = leetcode::parse(&case.input)
+                .map_err(|e| format!("❌ {}:{}: input: {}", path, case.line, e))?;
+            let output = leetcode::parse(&case.output)
+                .map_err(|e| format!("❌ {}:{}: output: {}", path, case.line + 1, e))?;
+            Ok((input, output))
+        })
+        .collect()
+}
+
+/// Appends a case, creating the file with a short header if needed
+pub fn append(path: &str, input: &str, output: &str) -> Result<(), String> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    let mut entry = String::new();
+    if existing.is_empty() {
+        entry.push_str(FILE_HEADER);
+    }
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        entry.push('\n');
+    }
+    entry.push_str(&format!("\nInput: {}\nOutput: {}\n", input, output));
+
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(entry.as_bytes()))
+        .map_err(|e| format!("❌ Failed to write {}: {}", path, e))
+}
+
+/// Cases file of the running bin. The practice bin shares the file of
+/// the problem it is an attempt at.
+pub fn path_for_current_bin() -> Option<String> {
+    let exe = std::env::current_exe().ok()?;
+    let mut bin_name = exe.file_stem()?.to_str()?.to_string();
+    if bin_name == practice::PRACTICE_BIN {
+        bin_name = practice::current_problem()?;
+    }
+    path_for_bin(&bin_name)
+}
+
+/// Cases file of the problem built as `bin_name`
+pub fn path_for_bin(bin_name: &str) -> Option<String> {
+    discovery::category_of(bin_name).map(|dir| format!("{}/{}.{}", dir, bin_name, EXTENSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_for_bin_finds_the_category_directory() {
+        assert_eq!(
+            path_for_bin("two_sum").as_deref(),
+            Some("01-arrays/two_sum.cases")
+        );
+        assert_eq!(path_for_bin("no_such_problem"), None);
+    }
+
+    fn pairs(cases: &[RawCase]) -> Vec<(usize, &str, &str)> {
+        cases
+            .iter()
+            .map(|case| (case.line, case.input.as_str(), case.output.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn blank_lines_comments_and_explanations_separate_cases() {
+        let text = "# header\n\nInput: nums = [1,2], k = 1\nOutput: [2,1]\nExplanation: rotate once\n\n\nInput: x = 3\n\nOutput: 3\n";
+        let cases = parse_file(text).unwrap();
+        assert_eq!(
+            pairs(&cases),
+            vec![(3, "nums = [1,2], k = 1", "[2,1]"), (8, "x = 3", "3")]
+        );
+    }
+
+    #[test]
+    fn wrapped_values_continue_on_the_next_lines() {
+        let text = "Input: grid = [[1,2],\n  [3,4]],\n  k = 2\nOutput: [[4,3],\n  [2,1]]\n\nInput: x = 1\nOutput: 1\n";
+        let cases = parse_file(text).unwrap();
+        assert_eq!(
+            pairs(&cases),
+            vec![
+                (1, "grid = [[1,2], [3,4]], k = 2", "[[4,3], [2,1]]"),
+                (7, "x = 1", "1"),
+            ]
+        );
+        let (grid, k): (Vec<Vec<i32>>, i32) = leetcode::parse(&cases[0].input).unwrap();
+        assert_eq!((grid, k), (vec![vec![1, 2], vec![3, 4]], 2));
+    }
+
+    #[test]
+    fn unpaired_and_stray_lines_are_errors() {
+        assert_eq!(
+            parse_file("Input: x = 1\n\nInput: x = 2\nOutput: 2\n").unwrap_err(),
+            "line 1: Input without an Output"
+        );
+        assert_eq!(
+            parse_file("Input: x = 1\n").unwrap_err(),
+            "line 1: Input without an Output"
+        );
+        assert_eq!(
+            parse_file("Output: 1\n").unwrap_err(),
+            "line 1: Output without an Input"
+        );
+        assert_eq!(
+            parse_file("Input: x = 1\nOutput: 1\n\n[2]\n").unwrap_err(),
+            "line 4: expected 'Input:' or 'Output:'"
+        );
+        assert!(parse_file("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn appended_cases_parse_back() {
+        let path = std::env::temp_dir().join(format!("dsa-cases-{}.cases", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = fs::remove_file(path);
+
+        append(path, "nums = [1,2]", "[2,1]").unwrap();
+        append(path, "nums = []", "[]").unwrap();
+
+        let text = fs::read_to_string(path).unwrap();
+        assert!(text.starts_with(FILE_HEADER));
+        assert_eq!(
+            pairs(&parse_file(&text).unwrap()),
+            vec![(4, "nums = [1,2]", "[2,1]"), (7, "nums = []", "[]")]
+        );
+
+        let loaded: Vec<((Vec<i32>,), Vec<i32>)> = load(path).unwrap();
+        assert_eq!(
+            loaded,
+            vec![((vec![1, 2],), vec![2, 1]), ((vec![],), vec![])]
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use serde_json::Value;
+
+/// A compiler error or warning from cargo's JSON output, reduced to what
+/// the summary shows
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// Bin or library target being compiled when it was reported
+    pub target: String,
+    pub level: String,
+    /// Error code such as "E0425", if any
+    pub code: Option<String>,
+    pub message: String,
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    /// Source line with the primary span
+    pub snippet: Option<String>,
+    /// Width of the highlighted part of the snippet
+    pub width: usize,
+    pub label: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        self.level == "error"
+    }
+}
+
+/// Reads a `compiler-message` line; other messages and notes like
+/// "aborting due to previous error" give `None`
+pub fn parse_message(message: &Value) -> Option<Diagnostic> {
+    if message["reason"] != "compiler-message" {
+        return None;
+    }
+    let inner = &message["message"];
+    let level = inner["level"].as_str()?;
+    if level != "error" && level != "warning" {
+        return None;
+    }
+
+    let text = inner["message"].as_str()?.to_string();
+    let primary = inner["spans"]
+        .as_array()
+        .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+    if primary.is_none() && is_tally(&text) {
+        return None;
+    }
+
+    let number = |span: &Value, key: &str| span[key].as_u64().unwrap_or(0) as usize;
+    let (snippet, width) = primary
+        .and_then(|span| span["text"].get(0))
+        .map(|line| {
+            let start = number(line, "highlight_start");
+            let end = number(line, "highlight_end");
+            (
+                line["text"].as_str().map(str::to_string),
+                end.saturating_sub(start).max(1),
+            )
+        })
+        .unwrap_or((None, 1));
+
+    Some(Diagnostic {
+        target: message["target"]["name"].as_str()?.to_string(),
+        level: level.to_string(),
+        code: inner["code"]["code"].as_str().map(str::to_string),
+        message: text,
+        file: primary.and_then(|span| span["file_name"].as_str().map(str::to_string)),
+        line: primary.map_or(0, |span| number(span, "line_start")),
+        column: primary.map_or(0, |span| number(span, "column_start")),
+        snippet,
+        width,
+        label: primary.and_then(|span| span["label"].as_str().map(str::to_string)),
+    })
+}
+
+/// rustc's closing summaries, "aborting due to 2 previous errors" and
+/// "1 warning emitted", which older toolchains report as spanless
+/// diagnostics
+fn is_tally(text: &str) -> bool {
+    if text.starts_with("aborting due to") {
+        return true;
+    }
+    let mut words = text.split(' ');
+    matches!(
+        (words.next(), words.next(), words.next(), words.next()),
+        (Some(count), Some("warning" | "warnings"), Some("emitted"), None)
+            if count.parse::<usize>().is_ok()
+    )
+}
+
+/// Renders the errors concisely, rustc style, followed by a count of
+/// warnings
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+
+    for diagnostic in diagnostics.iter().filter(|d| d.is_error()) {
+        let code = diagnostic
+            .code
+            .as_ref()
+            .map_or(String::new(), |code| format!("[{}]", code));
+        out.push_str(&format!("   error{}: {}\n", code, diagnostic.message));
+
+        if let Some(file) = &diagnostic.file {
+            out.push_str(&format!(
+                "     --> {}:{}:{}\n",
+                file, diagnostic.line, diagnostic.column
+            ));
+        }
+        if let Some(snippet) = &diagnostic.snippet {
+            let gutter = " ".repeat(diagnostic.line.to_string().len());
+            out.push_str(&format!(
+                "     {} | {}\n",
+                diagnostic.line,
+                snippet.trim_end()
+            ));
+            let marker = format!(
+                "     {} | {}{} {}",
+                gutter,
+                " ".repeat(diagnostic.column.saturating_sub(1)),
+                "^".repeat(diagnostic.width),
+                diagnostic.label.as_deref().unwrap_or("")
+            );
+            out.push_str(marker.trim_end());
+            out.push('\n');
+        }
+    }
+
+    let warnings = diagnostics.iter().filter(|d| !d.is_error()).count();
+    if warnings > 0 {
+        out.push_str(&format!("   ⚠️  {} warning(s)\n", warnings));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trimmed from `cargo build --message-format=json`
+    const MISMATCHED: &str = r#"{"reason": "compiler-message", "target": {"name": "diag"}, "message": {"message": "mismatched types", "code": {"code": "E0308"}, "level": "error", "spans": [{"file_name": "src/main.rs", "line_start": 2, "column_start": 12, "is_primary": false, "label": "expected due to this", "text": [{"text": "    let x: i32 = \"a\";", "highlight_start": 12, "highlight_end": 15}]}, {"file_name": "src/main.rs", "line_start": 2, "column_start": 18, "is_primary": true, "label": "expected `i32`, found `&str`", "text": [{"text": "    let x: i32 = \"a\";", "highlight_start": 18, "highlight_end": 21}]}]}}"#;
+    const UNUSED: &str = r#"{"reason": "compiler-message", "target": {"name": "diag"}, "message": {"message": "unused variable: `unused`", "code": {"code": "unused_variables"}, "level": "warning", "spans": [{"file_name": "src/main.rs", "line_start": 2, "column_start": 9, "is_primary": true, "label": null, "text": [{"text": "    let unused = 1;", "highlight_start": 9, "highlight_end": 15}]}]}}"#;
+    const EXPLAIN: &str = r#"{"reason": "compiler-message", "target": {"name": "diag"}, "message": {"message": "For more information about this error, try `rustc --explain E0308`.", "code": null, "level": "failure-note", "spans": []}}"#;
+
+    fn parse(line: &str) -> Option<Diagnostic> {
+        parse_message(&serde_json::from_str(line).unwrap())
+    }
+
+    fn spanless(level: &str, message: &str) -> String {
+        serde_json::json!({
+            "reason": "compiler-message",
+            "target": { "name": "diag" },
+            "message": { "message": message, "code": null, "level": level, "spans": [] }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn multi_span_errors_use_the_primary_span() {
+        let diagnostic = parse(MISMATCHED).unwrap();
+        assert!(diagnostic.is_error());
+        assert_eq!(diagnostic.target, "diag");
+        assert_eq!(diagnostic.code.as_deref(), Some("E0308"));
+        assert_eq!(diagnostic.file.as_deref(), Some("src/main.rs"));
+        assert_eq!(
+            (diagnostic.line, diagnostic.column, diagnostic.width),
+            (2, 18, 3)
+        );
+        assert_eq!(
+            diagnostic.label.as_deref(),
+            Some("expected `i32`, found `&str`")
+        );
+
+        assert_eq!(
+            render(&[diagnostic]),
+            "   error[E0308]: mismatched types\n     --> src/main.rs:2:18\n     2 |     let x: i32 = \"a\";\n       |                  ^^^ expected `i32`, found `&str`\n"
+        );
+    }
+
+    #[test]
+    fn warnings_are_only_counted() {
+        let warning = parse(UNUSED).unwrap();
+        assert!(!warning.is_error());
+        assert_eq!(render(&[warning.clone(), warning]), "   ⚠️  2 warning(s)\n");
+        assert_eq!(render(&[]), "");
+    }
+
+    #[test]
+    fn notes_other_reasons_and_tallies_are_skipped() {
+        assert!(parse(EXPLAIN).is_none());
+        assert!(parse(r#"{"reason": "build-finished", "success": false}"#).is_none());
+        assert!(parse(&spanless("error", "aborting due to 2 previous errors")).is_none());
+        assert!(parse(&spanless("warning", "1 warning emitted")).is_none());
+        assert!(parse(&spanless("warning", "12 warnings emitted")).is_none());
+
+        let spanless_warning = parse(&spanless("warning", "unused manifest key emitted")).unwrap();
+        assert_eq!(spanless_warning.file, None);
+        assert_eq!(spanless_warning.snippet, None);
+    }
+}
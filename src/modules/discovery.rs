@@ -1,4 +1,6 @@
+use crate::modules::source::{self, Signature};
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, Clone)]
 pub struct Problem {
@@ -12,9 +14,31 @@ impl Problem {
     pub fn display_name(&self) -> String {
         format!("{:<30} 📁 {}", self.name, self.category)
     }
+
+    /// Category without its ordering prefix: "01-arrays" -> "arrays"
+    pub fn category_label(&self) -> &str {
+        self.category
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .trim_start_matches('-')
+    }
+
+    /// Methods of the solution's `impl Solution`, parsed from the file on
+    /// each call
+    pub fn signatures(&self) -> Vec<Signature> {
+        fs::read_to_string(&self.path)
+            .map(|src| source::solution_signatures(&src))
+            .unwrap_or_default()
+    }
+
+    /// Path of a file stored next to the solution, e.g. "md" gives
+    /// "01-arrays/two_sum.md"
+    pub fn sidecar_path(&self, extension: &str) -> String {
+        format!("{}/{}.{}", self.category, self.bin_name, extension)
+    }
 }
 
 /// Discovers all LeetCode problems in numbered directories
+#[allow(clippy::collapsible_if, clippy::unnecessary_map_or)]
 pub fn discover_problems() -> Vec<Problem> {
     let mut problems = Vec::new();
 
@@ -61,6 +85,17 @@ pub fn discover_problems() -> Vec<Problem> {
     problems
 }
 
+/// Category directory that holds `<bin_name>.rs`, found without listing
+/// or reading the solutions
+pub fn category_of(bin_name: &str) -> Option<String> {
+    fs::read_dir(".")
+        .ok()?
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|dir| dir.starts_with(|c: char| c.is_ascii_digit()))
+        .find(|dir| Path::new(dir).join(format!("{}.rs", bin_name)).is_file())
+}
+
 /// Lists problems by category (e.g., "array", "linked-list")
 pub fn list_by_category(category: &str) -> Vec<Problem> {
     discover_problems()
@@ -0,0 +1,117 @@
+//! Compares the `[[bin]]` entries in Cargo.toml with the solution files on
+//! disk. Uses only std so `build.rs` can include it as well.
+
+/// How Cargo.toml differs from the numbered category directories
+#[derive(Debug, Default)]
+pub struct Drift {
+    /// Solution files without a `[[bin]]` entry
+    pub new: Vec<String>,
+    /// `[[bin]]` entries whose file is gone
+    pub missing: Vec<String>,
+    /// `(old, new)` paths: a missing entry and a new file that are the only
+    /// change in their directory
+    pub renamed: Vec<(String, String)>,
+}
+
+impl Drift {
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty() && self.missing.is_empty() && self.renamed.is_empty()
+    }
+
+    /// One line per problem, grouped by kind of change
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        for path in &self.new {
+            out.push_str(&format!("   + new:     {}\n", path));
+        }
+        for path in &self.missing {
+            out.push_str(&format!("   - missing: {}\n", path));
+        }
+        for (old, new) in &self.renamed {
+            out.push_str(&format!("   ~ renamed: {} -> {}\n", old, new));
+        }
+        out
+    }
+}
+
+/// `(name, path)` of every `[[bin]]` entry in the manifest
+pub fn registered_bins(manifest: &str) -> Vec<(String, String)> {
+    let mut bins = Vec::new();
+    let mut in_bin = false;
+    let mut name = None;
+
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_bin = line == "[[bin]]";
+            name = None;
+            continue;
+        }
+        if !in_bin {
+            continue;
+        }
+        if let Some(value) = string_value(line, "name") {
+            name = Some(value);
+        } else if let Some(path) = string_value(line, "path")
+            && let Some(name) = name.take()
+        {
+            bins.push((name, path));
+        }
+    }
+    bins
+}
+
+/// Compares the manifest with `files`, the solution paths relative to the
+/// crate root (`01-arrays/two_sum.rs`). Bins outside numbered category
+/// directories, like the practice workspace, are not considered.
+pub fn compare(manifest: &str, files: &[String]) -> Drift {
+    let registered: Vec<String> = registered_bins(manifest)
+        .into_iter()
+        .map(|(_, path)| path)
+        .filter(|path| is_category_path(path))
+        .collect();
+
+    let mut drift = Drift {
+        new: files
+            .iter()
+            .filter(|path| !registered.contains(path))
+            .cloned()
+            .collect(),
+        missing: registered
+            .iter()
+            .filter(|path| !files.contains(path))
+            .cloned()
+            .collect(),
+        renamed: Vec::new(),
+    };
+
+    let directory = |path: &str| path.rsplit_once('/').map(|(dir, _)| dir.to_string());
+    for old in drift.missing.clone() {
+        let dir = directory(&old);
+        let same_dir = |path: &&String| directory(path) == dir;
+        let olds = drift.missing.iter().filter(same_dir).count();
+        let news: Vec<&String> = drift.new.iter().filter(same_dir).collect();
+        if olds == 1 && news.len() == 1 {
+            let new = news[0].clone();
+            drift.missing.retain(|path| *path != old);
+            drift.new.retain(|path| *path != new);
+            drift.renamed.push((old, new));
+        }
+    }
+
+    drift.new.sort();
+    drift.missing.sort();
+    drift
+}
+
+fn is_category_path(path: &str) -> bool {
+    path.starts_with(|c: char| c.is_ascii_digit()) && path.contains('/')
+}
+
+/// Reads `key = "value"`
+fn string_value(line: &str, key: &str) -> Option<String> {
+    let (left, right) = line.split_once('=')?;
+    if left.trim() != key {
+        return None;
+    }
+    Some(right.trim().trim_matches('"').to_string())
+}
@@ -0,0 +1,188 @@
+use crate::modules::discovery::Problem;
+use crate::modules::{cases, runner, source};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Overrides how the line is passed to the editor, e.g. `--goto {file}:{line}`
+pub const LINE_TEMPLATE_VAR: &str = "DSA_EDITOR_LINE";
+
+/// Where to put the cursor when opening a problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jump {
+    /// The first `impl Solution` block
+    Solution,
+    /// The first failing case, or the first compile error
+    Failing,
+}
+
+/// A file and 1-based line to open
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub file: String,
+    pub line: usize,
+}
+
+/// `$VISUAL`, else `$EDITOR`, ignoring empty values
+pub fn editor() -> Option<String> {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+}
+
+/// Arguments template with `{file}` and `{line}` placeholders: the
+/// `DSA_EDITOR_LINE` variable if set, else a default for known editors.
+/// Unknown editors just get the file.
+pub fn line_template(editor: &str) -> String {
+    if let Ok(template) = std::env::var(LINE_TEMPLATE_VAR)
+        && !template.trim().is_empty()
+    {
+        return template;
+    }
+
+    let program = editor.split_whitespace().next().unwrap_or("");
+    let program = Path::new(program)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("");
+    match program {
+        "vi" | "vim" | "nvim" | "gvim" | "nano" | "emacs" | "emacsclient" | "micro" | "hx"
+        | "kak" | "helix" => "+{line} {file}",
+        "code" | "code-insiders" | "codium" | "cursor" | "windsurf" => "--goto {file}:{line}",
+        "subl" | "zed" => "{file}:{line}",
+        "idea" | "clion" | "rustrover" => "--line {line} {file}",
+        _ => "{file}",
+    }
+    .to_string()
+}
+
+/// Finds the line to open for `jump`. Finding a failing case builds and
+/// runs the problem; when everything passes it falls back to the solution.
+pub fn locate(problem: &Problem, jump: Jump) -> Result<Location, String> {
+    let src = fs::read_to_string(&problem.path)
+        .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+
+    if jump == Jump::Failing {
+        if let Some(location) = first_failure(problem, &src)? {
+            return Ok(location);
+        }
+        println!(
+            "✅ {} passes every case, opening the solution",
+            problem.name
+        );
+    }
+
+    let line = source::solution_impls(&src)
+        .first()
+        .map_or(1, |&(start, _)| src[..start].lines().count() + 1);
+    Ok(Location {
+        file: problem.path.clone(),
+        line,
+    })
+}
+
+/// Opens the problem in the user's editor. Without one configured, prints
+/// the location instead.
+pub fn open(problem: &Problem, jump: Jump) -> Result<(), String> {
+    open_location(&locate(problem, jump)?)
+}
+
+/// Opens any file at a line in the user's editor, or prints the location
+/// when none is configured
+pub fn open_location(location: &Location) -> Result<(), String> {
+    let Some(editor) = editor() else {
+        println!("📄 {}:{}", location.file, location.line);
+        println!("💡 Set $VISUAL or $EDITOR to open it directly");
+        return Ok(());
+    };
+
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or_default();
+    let mut args: Vec<String> = words.map(str::to_string).collect();
+    args.extend(
+        line_template(&editor)
+            .replace("{file}", &location.file)
+            .replace("{line}", &location.line.to_string())
+            .split_whitespace()
+            .map(str::to_string),
+    );
+
+    println!("✏️  {} {}", program, args.join(" "));
+    let status = Command::new(program)
+        .args(&args)
+        .status()
+        .map_err(|e| format!("❌ Failed to start editor '{}': {}", program, e))?;
+    if !status.success() {
+        return Err(format!("❌ Editor '{}' exited with {}", program, status));
+    }
+    Ok(())
+}
+
+/// Line of the first compile error or failing case, if any
+fn first_failure(problem: &Problem, src: &str) -> Result<Option<Location>, String> {
+    println!(
+        "🔨 Running {} to find the first failure...",
+        problem.bin_name
+    );
+    let build = runner::build_bins(std::slice::from_ref(&problem.bin_name), false)?;
+
+    if build.artifacts.is_empty() {
+        return Ok(build
+            .diagnostics
+            .iter()
+            .find(|d| d.is_error() && d.file.is_some())
+            .map(|d| Location {
+                file: d.file.clone().unwrap_or_default(),
+                line: d.line.max(1),
+            }));
+    }
+
+    let results = runner::run_artifacts(&build.artifacts, &[]);
+    let Some(case) = results
+        .first()
+        .and_then(|result| failed_case_number(&result.output))
+    else {
+        return Ok(None);
+    };
+
+    // The harness runs the cases in `fn main` first, then the `.cases` file
+    let calls = source::harness_case_calls(src);
+    if let Some(dynamic) = source::dynamic_case_call(src)
+        && case > dynamic.after
+    {
+        println!(
+            "💡 Case {} comes from {}(..) or later; its line isn't known",
+            case, dynamic.method
+        );
+        return Ok(Some(Location {
+            file: problem.path.clone(),
+            line: dynamic.line.max(1),
+        }));
+    }
+    if let Some(call) = calls.get(case - 1) {
+        return Ok(Some(Location {
+            file: problem.path.clone(),
+            line: call.line.max(1),
+        }));
+    }
+
+    let sidecar = problem.sidecar_path(cases::EXTENSION);
+    let stored = fs::read_to_string(&sidecar)
+        .ok()
+        .and_then(|text| cases::parse_file(&text).ok())
+        .unwrap_or_default();
+    Ok(stored.get(case - 1 - calls.len()).map(|raw| Location {
+        file: sidecar.clone(),
+        line: raw.line,
+    }))
+}
+
+/// `N` from the harness's first "❌ <approach> failed case N" message
+fn failed_case_number(output: &str) -> Option<usize> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once(" failed case "))
+        .find_map(|(_, rest)| rest.trim().parse().ok())
+        .filter(|&n| n > 0)
+}
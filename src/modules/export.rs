@@ -0,0 +1,358 @@
+use crate::modules::discovery::Problem;
+use crate::modules::metadata::{self, Metadata};
+use crate::modules::notes::{self, Notes};
+use crate::modules::source;
+use std::fs;
+use std::path::Path;
+
+/// Shared stylesheet, inlined so the site works from `file://`
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; background: #1e1e1e; color: #e1e4e8; }
+a { color: #ce412b; }
+h1, h2, h3 { color: #ce412b; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }
+th, td { text-align: left; padding: 0.4rem 0.8rem; border-bottom: 1px solid #30363d; }
+pre { background: #0d1117; padding: 1rem; overflow-x: auto; border-radius: 6px; }
+code { font-family: ui-monospace, monospace; }
+.k { color: #ff7b72; } .s { color: #a5d6ff; } .c { color: #8b949e; font-style: italic; }
+.n { color: #79c0ff; } .t { color: #ffa657; } .m { color: #d2a8ff; }
+";
+
+/// Renders a static site for `problems` into `dir`
+pub fn export_html(problems: &[Problem], dir: &Path) -> Result<(), String> {
+    let pages = dir.join("problems");
+    fs::create_dir_all(&pages)
+        .map_err(|e| format!("❌ Failed to create {}: {}", pages.display(), e))?;
+
+    for problem in problems {
+        let source = fs::read_to_string(&problem.path)
+            .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+        let page = pages.join(format!("{}.html", problem.bin_name));
+
+        fs::write(&page, render_problem_page(problem, &source))
+            .map_err(|e| format!("❌ Failed to write {}: {}", page.display(), e))?;
+    }
+
+    let index = dir.join("index.html");
+    fs::write(&index, render_index(problems))
+        .map_err(|e| format!("❌ Failed to write {}: {}", index.display(), e))?;
+
+    println!(
+        "✅ Exported {} problems to {}",
+        problems.len(),
+        index.display()
+    );
+    Ok(())
+}
+
+/// Concatenates every solution into a single Markdown study guide
+pub fn export_markdown(problems: &[Problem], path: &Path) -> Result<(), String> {
+    let mut out = String::from("# 🦀 DSA Study Guide\n");
+
+    let mut categories: Vec<&str> = problems.iter().map(|p| p.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+
+    for category in categories {
+        let in_category: Vec<&Problem> =
+            problems.iter().filter(|p| p.category == category).collect();
+        out.push_str(&format!("\n## {}\n", in_category[0].category_label()));
+
+        for problem in in_category {
+            let source = fs::read_to_string(&problem.path)
+                .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+            out.push_str(&render_markdown_problem(problem, &source));
+        }
+    }
+
+    fs::write(path, out).map_err(|e| format!("❌ Failed to write {}: {}", path.display(), e))?;
+
+    println!(
+        "✅ Exported {} problems to {}",
+        problems.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn render_markdown_problem(problem: &Problem, source: &str) -> String {
+    let meta = metadata::load(problem);
+    let notes = notes::load(problem);
+    let complexity = notes.clone().unwrap_or_default();
+    let mut out = format!("\n### {}\n\n", meta.title_or(problem));
+
+    let mut facts = vec![format!("**Difficulty:** {}", meta.difficulty_label())];
+    if let Some(time) = complexity.time_or(&meta) {
+        facts.push(format!("**Time:** {}", time));
+    }
+    if let Some(space) = complexity.space_or(&meta) {
+        facts.push(format!("**Space:** {}", space));
+    }
+    if let Some(link) = &meta.link {
+        facts.push(format!("[LeetCode]({})", link));
+    }
+    out.push_str(&format!("{}\n\n", facts.join(" · ")));
+
+    if let Some(description) = &meta.description {
+        out.push_str(&format!("{}\n\n", description));
+    }
+
+    if let Some(notes) = notes.filter(|n| !note_lines(n).is_empty()) {
+        out.push_str("#### Notes\n\n");
+        for line in note_lines(&notes) {
+            // Nest the notes' own headings below "#### Notes"
+            if line.starts_with('#') {
+                out.push_str("###");
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    out.push_str(&format!(
+        "#### Solution\n\n```rust\n{}\n```\n",
+        final_solution(source)
+    ));
+
+    // Approach modules first, then anything still commented out
+    let alternatives: Vec<(String, String, String)> = source::approach_modules(source)
+        .into_iter()
+        .map(|m| (m.name.replace('_', " "), m.note, m.code))
+        .chain(
+            source::commented_approaches(source)
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (format!("Approach {}", i + 1), c.note, c.code)),
+        )
+        .collect();
+
+    if !alternatives.is_empty() {
+        out.push_str("\n#### Alternative approaches\n");
+        for (name, note, code) in alternatives {
+            out.push_str(&format!("\n**{}**", name));
+            if !note.is_empty() {
+                out.push_str(&format!(" — {}", note));
+            }
+            out.push_str(&format!("\n\n```rust\n{}\n```\n", code));
+        }
+    }
+
+    out
+}
+
+/// Body of the notes without a leading `# Title`, which repeats the
+/// problem heading
+fn note_lines(notes: &Notes) -> Vec<&str> {
+    let mut lines: Vec<&str> = notes.body.lines().collect();
+    if lines.first().is_some_and(|line| line.starts_with("# ")) {
+        lines.remove(0);
+    }
+    let start = lines.iter().position(|l| !l.trim().is_empty());
+    let end = lines.iter().rposition(|l| !l.trim().is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+/// Solution code without the harness, metadata header or other approaches
+fn final_solution(source: &str) -> String {
+    let source = source::without_approaches(source);
+    let source = source.as_str();
+
+    let end = source::main_body(source)
+        .and_then(|(open, _)| source[..open].rfind("fn main"))
+        .unwrap_or(source.len());
+
+    source::strip_line_comments(&source[..end])
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//!"))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn render_index(problems: &[Problem]) -> String {
+    let mut body = String::from("<h1>🦀 DSA Solutions</h1>\n");
+
+    let mut categories: Vec<&str> = problems.iter().map(|p| p.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+
+    for category in categories {
+        let in_category: Vec<&Problem> =
+            problems.iter().filter(|p| p.category == category).collect();
+
+        body.push_str(&format!(
+            "<h2>{} ({})</h2>\n<table>\n<tr><th>Problem</th><th>Difficulty</th><th>Time</th><th>Space</th></tr>\n",
+            escape(in_category[0].category_label()),
+            in_category.len()
+        ));
+        for problem in in_category {
+            let meta = metadata::load(problem);
+            body.push_str(&format!(
+                "<tr><td><a href=\"problems/{}.html\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                problem.bin_name,
+                escape(meta.title_or(problem)),
+                escape(meta.difficulty_label()),
+                escape(meta.time.as_deref().unwrap_or("-")),
+                escape(meta.space.as_deref().unwrap_or("-")),
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    page("DSA Solutions", &body)
+}
+
+fn render_problem_page(problem: &Problem, source: &str) -> String {
+    let meta = metadata::load(problem);
+    let title = meta.title_or(problem);
+
+    let mut body = format!(
+        "<p><a href=\"../index.html\">← All problems</a></p>\n<h1>{}</h1>\n",
+        escape(title)
+    );
+    body.push_str(&render_metadata(problem, &meta));
+
+    if let Some(notes) = notes::load(problem).filter(|n| !note_lines(n).is_empty()) {
+        body.push_str("<h2>Notes</h2>\n");
+        let text = note_lines(&notes).join("\n");
+        for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+            let paragraph = paragraph.trim();
+            match paragraph.strip_prefix("## ") {
+                Some(heading) if !heading.contains('\n') => {
+                    body.push_str(&format!("<h3>{}</h3>\n", escape(heading)));
+                }
+                _ => body.push_str(&format!("<p>{}</p>\n", escape(paragraph))),
+            }
+        }
+    }
+
+    let cases = source::harness_cases(source);
+    if !cases.is_empty() {
+        body.push_str("<h2>Test cases</h2>\n<ol>\n");
+        for case in cases {
+            body.push_str(&format!("<li><code>{}</code></li>\n", escape(&case)));
+        }
+        body.push_str("</ol>\n");
+    }
+
+    body.push_str(&format!(
+        "<h2>Solution</h2>\n<pre><code>{}</code></pre>\n",
+        highlight(source)
+    ));
+
+    page(title, &body)
+}
+
+fn render_metadata(problem: &Problem, meta: &Metadata) -> String {
+    let complexity = notes::load(problem).unwrap_or_default();
+    let mut rows = vec![
+        ("Category", escape(problem.category_label())),
+        ("Difficulty", escape(meta.difficulty_label())),
+    ];
+    if let Some(time) = complexity.time_or(meta) {
+        rows.push(("Time", escape(time)));
+    }
+    if let Some(space) = complexity.space_or(meta) {
+        rows.push(("Space", escape(space)));
+    }
+    if let Some(link) = &meta.link {
+        rows.push(("LeetCode", format!("<a href=\"{0}\">{0}</a>", escape(link))));
+    }
+    rows.push(("Source", format!("<code>{}</code>", escape(&problem.path))));
+
+    let mut out = String::from("<table>\n");
+    for (label, value) in rows {
+        out.push_str(&format!("<tr><th>{}</th><td>{}</td></tr>\n", label, value));
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "else", "enum", "false", "fn", "for", "if", "impl", "in",
+    "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "trait", "true", "type", "use", "where", "while",
+];
+
+/// Wraps Rust tokens in `<span>`s for the stylesheet classes
+fn highlight(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len() * 2);
+    let mut i = 0;
+
+    let span = |out: &mut String, class: &str, text: &str| {
+        out.push_str(&format!(
+            "<span class=\"{}\">{}</span>",
+            class,
+            escape(text)
+        ));
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            span(&mut out, "c", &chars[start..i].iter().collect::<String>());
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            span(&mut out, "s", &chars[start..i].iter().collect::<String>());
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            span(&mut out, "n", &chars[start..i].iter().collect::<String>());
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+
+            if KEYWORDS.contains(&word.as_str()) {
+                span(&mut out, "k", &word);
+            } else if chars.get(i) == Some(&'!') {
+                span(&mut out, "m", &word);
+            } else if word.starts_with(char::is_uppercase) {
+                span(&mut out, "t", &word);
+            } else {
+                out.push_str(&word);
+            }
+        } else {
+            out.push_str(&escape(&c.to_string()));
+            i += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,338 @@
+use crate::modules::cases;
+use crate::modules::generate::Rng;
+use crate::modules::leetcode::{self, ToLeetCode};
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Upper bound on accepted shrink steps, so shrinking always terminates
+const MAX_SHRINK_STEPS: usize = 1000;
+
+/// Produces "smaller" candidates of a value, simplest first
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+impl Shrink for i32 {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        if *self != 0 {
+            out.push(0);
+        }
+        if self.abs() > 1 {
+            out.push(self / 2);
+            out.push(self - self.signum());
+        }
+        if *self < 0 {
+            out.push(self.saturating_neg());
+        }
+        out
+    }
+}
+
+impl Shrink for i64 {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        if *self != 0 {
+            out.push(0);
+        }
+        if self.abs() > 1 {
+            out.push(self / 2);
+            out.push(self - self.signum());
+        }
+        out
+    }
+}
+
+impl Shrink for usize {
+    fn shrink(&self) -> Vec<Self> {
+        match *self {
+            0 => Vec::new(),
+            1 => vec![0],
+            n => vec![0, n / 2, n - 1],
+        }
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { Vec::new() }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        if *self == 'a' { Vec::new() } else { vec!['a'] }
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        let chars: Vec<char> = self.chars().collect();
+        chars
+            .shrink()
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        let n = self.len();
+
+        // Drop halves first, then single elements, then shrink elements
+        if n > 1 {
+            out.push(self[..n / 2].to_vec());
+            out.push(self[n / 2..].to_vec());
+        }
+        for i in 0..n {
+            let mut smaller = self.clone();
+            smaller.remove(i);
+            out.push(smaller);
+        }
+        for i in 0..n {
+            for candidate in self[i].shrink() {
+                let mut smaller = self.clone();
+                smaller[i] = candidate;
+                out.push(smaller);
+            }
+        }
+        out
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Option<T> {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            None => Vec::new(),
+            Some(value) => std::iter::once(None)
+                .chain(value.shrink().into_iter().map(Some))
+                .collect(),
+        }
+    }
+}
+
+macro_rules! impl_shrink_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: Shrink + Clone),+> Shrink for ($($name,)+) {
+            fn shrink(&self) -> Vec<Self> {
+                let mut out = Vec::new();
+                $(
+                    for candidate in self.$index.shrink() {
+                        let mut smaller = self.clone();
+                        smaller.$index = candidate;
+                        out.push(smaller);
+                    }
+                )+
+                out
+            }
+        }
+    };
+}
+
+impl_shrink_tuple!(A 0);
+impl_shrink_tuple!(A 0, B 1);
+impl_shrink_tuple!(A 0, B 1, C 2);
+impl_shrink_tuple!(A 0, B 1, C 2, D 3);
+
+/// Fuzzing settings, parsed from the bin's command line by the harness
+#[derive(Debug, Clone)]
+pub struct FuzzConfig {
+    pub iterations: usize,
+    pub seed: u64,
+    /// Largest `n` passed to the generator
+    pub max_len: usize,
+    /// Cases file the shrunk counterexample is appended to
+    pub save: Option<String>,
+}
+
+impl FuzzConfig {
+    /// Returns the config when `args` contains `--fuzz`
+    pub fn from_args(args: &[String]) -> Option<FuzzConfig> {
+        if !args.iter().any(|a| a == "--fuzz") {
+            return None;
+        }
+
+        let mut config = FuzzConfig {
+            iterations: 2000,
+            seed: Rng::from_time().next_u64(),
+            max_len: 8,
+            save: None,
+        };
+        for (i, arg) in args.iter().enumerate() {
+            let value = args.get(i + 1).cloned();
+            match arg.as_str() {
+                "--iterations" => {
+                    if let Some(n) = value.and_then(|v| v.parse().ok()) {
+                        config.iterations = n;
+                    }
+                }
+                "--seed" => {
+                    if let Some(seed) = value.and_then(|v| v.parse().ok()) {
+                        config.seed = seed;
+                    }
+                }
+                "--max-len" => {
+                    if let Some(n) = value.and_then(|v| v.parse().ok()) {
+                        config.max_len = n;
+                    }
+                }
+                "--save" => config.save = value,
+                _ => {}
+            }
+        }
+        Some(config)
+    }
+
+    /// Command-line form understood by [`FuzzConfig::from_args`]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--fuzz".to_string(),
+            "--iterations".to_string(),
+            self.iterations.to_string(),
+            "--seed".to_string(),
+            self.seed.to_string(),
+            "--max-len".to_string(),
+            self.max_len.to_string(),
+        ];
+        if let Some(save) = &self.save {
+            args.extend(["--save".to_string(), save.clone()]);
+        }
+        args
+    }
+}
+
+/// Everything the fuzzer needs from a harness
+pub struct Target<'a, I, O> {
+    pub title: &'a str,
+    pub approaches: Vec<(&'a str, &'a dyn Fn(I) -> O)>,
+    pub oracle: &'a dyn Fn(I) -> O,
+    pub generator: &'a dyn Fn(&mut Rng, usize) -> I,
+    pub shrink: fn(&I) -> Vec<I>,
+    pub constraint: Option<fn(&I) -> bool>,
+    pub normalize: Option<fn(O) -> O>,
+}
+
+/// Compares every approach against the oracle on random inputs, shrinking
+/// and saving the first counterexample. Returns whether all cases passed.
+pub fn run<I: Clone + Debug + ToLeetCode, O: PartialEq + Debug + ToLeetCode>(
+    target: &Target<I, O>,
+    config: &FuzzConfig,
+) -> bool {
+    println!(
+        "🎲 Fuzzing {}: {} iterations, n ≤ {}, seed {}\n",
+        target.title, config.iterations, config.max_len, config.seed
+    );
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rng = Rng::new(config.seed);
+    let mut checked = 0;
+    let mut failure = None;
+
+    'cases: for _ in 0..config.iterations {
+        let n = rng.below(config.max_len + 1);
+        let Ok(input) = panic::catch_unwind(AssertUnwindSafe(|| (target.generator)(&mut rng, n)))
+        else {
+            continue;
+        };
+        if target.constraint.is_some_and(|valid| !valid(&input)) {
+            continue;
+        }
+
+        checked += 1;
+        for &(name, approach) in &target.approaches {
+            if fails(target, approach, &input) {
+                failure = Some((name, approach, input));
+                break 'cases;
+            }
+        }
+    }
+
+    let passed = match failure {
+        None => {
+            println!("✅ {} random cases matched the oracle", checked);
+            true
+        }
+        Some((name, approach, input)) => {
+            let minimal = shrink(target, approach, input);
+            report_failure(target, name, approach, &minimal, config);
+            false
+        }
+    };
+
+    panic::set_hook(hook);
+    passed
+}
+
+/// Evaluates `f`, normalizing the output; `None` when it panicked
+fn evaluate<I: Clone, O>(target: &Target<I, O>, f: &dyn Fn(I) -> O, input: &I) -> Option<O> {
+    let input = input.clone();
+    let output = panic::catch_unwind(AssertUnwindSafe(|| f(input))).ok()?;
+    Some(match target.normalize {
+        Some(normalize) => normalize(output),
+        None => output,
+    })
+}
+
+/// Whether `approach` disagrees with the oracle (or panics) on `input`.
+/// Inputs the oracle itself cannot handle never count as failures.
+fn fails<I: Clone, O: PartialEq>(
+    target: &Target<I, O>,
+    approach: &dyn Fn(I) -> O,
+    input: &I,
+) -> bool {
+    let Some(expected) = evaluate(target, target.oracle, input) else {
+        return false;
+    };
+    evaluate(target, approach, input).as_ref() != Some(&expected)
+}
+
+/// Greedily takes the first smaller candidate that still fails
+fn shrink<I: Clone, O: PartialEq>(target: &Target<I, O>, approach: &dyn Fn(I) -> O, input: I) -> I {
+    let mut current = input;
+
+    for _ in 0..MAX_SHRINK_STEPS {
+        let smaller = (target.shrink)(&current).into_iter().find(|candidate| {
+            target.constraint.is_none_or(|valid| valid(candidate))
+                && fails(target, approach, candidate)
+        });
+
+        match smaller {
+            Some(candidate) => current = candidate,
+            None => break,
+        }
+    }
+
+    current
+}
+
+fn report_failure<I: Clone + Debug + ToLeetCode, O: PartialEq + Debug + ToLeetCode>(
+    target: &Target<I, O>,
+    name: &str,
+    approach: &dyn Fn(I) -> O,
+    input: &I,
+    config: &FuzzConfig,
+) {
+    let expected = evaluate(target, target.oracle, input);
+    let got = evaluate(target, approach, input);
+
+    println!("❌ {} disagrees with the oracle", name);
+    println!("   Minimal input: {:?}", input);
+    println!("   Expected:      {:?}", expected);
+    match &got {
+        Some(got) => println!("   Got:           {:?}", got),
+        None => println!("   Got:           💥 panicked"),
+    }
+    println!("   Reproduce with --seed {}", config.seed);
+
+    if let (Some(path), Some(expected)) = (&config.save, expected) {
+        let input = leetcode::format_args(input);
+        match cases::append(path, &input, &expected.to_value().to_string()) {
+            Ok(()) => println!("\n💾 Added regression case to {}", path),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
@@ -1,15 +1,33 @@
 use crate::modules::discovery::Problem;
+use crate::modules::notes;
 use std::io::Write;
 use std::process::{Command, Stdio};
 
-/// Launches FZF with all problems, returns selected problem
-pub fn run_fzf(problems: &[Problem]) -> Option<Problem> {
+/// Shows the solution's `//!` header, then its notes if it has any
+const PREVIEW: &str = "sed -n 's|^//! *||p' {2}; echo; cat {3} 2>/dev/null || echo '📝 No notes yet (cargo run note <name>)'";
+
+/// What to do with the problem picked in FZF
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Enter
+    Run,
+    /// Ctrl+E
+    Edit,
+}
+
+/// Launches FZF with all problems, returns selected problem and the key
+/// it was picked with
+pub fn run_fzf(problems: &[Problem]) -> Option<(Problem, Action)> {
     println!("🎯 Starting FZF fuzzy finder...\n");
 
     let fzf_args = get_rust_theme();
 
     let mut child = Command::new("fzf")
         .args(&fzf_args)
+        .args(["--expect", "ctrl-e"])
+        // Lines carry the solution and notes paths as hidden fields
+        .args(["--delimiter", "\t", "--with-nth", "1"])
+        .args(["--preview", PREVIEW, "--preview-window", "right:50%:wrap"])
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .spawn()
@@ -18,7 +36,14 @@ pub fn run_fzf(problems: &[Problem]) -> Option<Problem> {
     // Write problem list to FZF stdin
     if let Some(mut stdin) = child.stdin.take() {
         for problem in problems {
-            writeln!(stdin, "{}", problem.display_name()).ok()?;
+            writeln!(
+                stdin,
+                "{}\t{}\t{}",
+                problem.display_name(),
+                problem.path,
+                problem.sidecar_path(notes::EXTENSION)
+            )
+            .ok()?;
         }
     }
 
@@ -30,18 +55,26 @@ pub fn run_fzf(problems: &[Problem]) -> Option<Problem> {
         return None;
     }
 
-    let selection = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    // With --expect, the first line is the key pressed (empty for Enter)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let action = match lines.next() {
+        Some("ctrl-e") => Action::Edit,
+        _ => Action::Run,
+    };
+    let selection = lines.next().unwrap_or("").trim().to_string();
 
     if selection.is_empty() {
         println!("👋 No selection made.");
         return None;
     }
 
-    // Parse selection: "Two Sum                        📁 01-array"
-    let selected_name = selection.split("📁").next()?.trim();
+    // Parse selection: "Two Sum    📁 01-array\t01-array/two_sum.rs\t..."
+    let selected_path = selection.split('\t').nth(1)?;
 
-    // Find matching problem by display name
-    problems.iter().find(|p| p.name == selected_name).cloned()
+    // Find matching problem by path
+    let problem = problems.iter().find(|p| p.path == selected_path).cloned()?;
+    Some((problem, action))
 }
 
 /// Returns FZF theme styled for Rust
@@ -57,7 +90,7 @@ fn get_rust_theme() -> Vec<String> {
         "--marker".to_string(),
         "✓".to_string(),
         "--header".to_string(),
-        "Use ↑↓ to navigate • Enter to run • Ctrl+C to exit".to_string(),
+        "Use ↑↓ to navigate • Enter to run • Ctrl+E to edit • Ctrl+C to exit".to_string(),
         "--color".to_string(),
         "fg:#CE412B".to_string(), // Rust orange
         "--color".to_string(),
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Small deterministic xorshift64* generator, good enough for test inputs
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would stay zero forever
+        Rng {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Seeds from the current time
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Rng::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform index in `0..bound`; `bound` must be non-zero
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Uniform value in an inclusive range
+    pub fn int(&mut self, range: RangeInclusive<i32>) -> i32 {
+        let (lo, hi) = (*range.start() as i64, *range.end() as i64);
+        let span = (hi - lo + 1) as u64;
+        (lo + (self.next_u64() % span) as i64) as i32
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// `len` values drawn from `range`
+    pub fn vec(&mut self, len: usize, range: RangeInclusive<i32>) -> Vec<i32> {
+        (0..len).map(|_| self.int(range.clone())).collect()
+    }
+
+    /// `len` pairwise distinct values drawn from `range`, in random order.
+    /// Returns fewer values when the range is too small.
+    pub fn distinct_vec(&mut self, len: usize, range: RangeInclusive<i32>) -> Vec<i32> {
+        let available = (*range.end() as i64 - *range.start() as i64 + 1) as usize;
+        let len = len.min(available);
+
+        let mut seen = HashSet::with_capacity(len);
+        let mut out = Vec::with_capacity(len);
+        while out.len() < len {
+            let value = self.int(range.clone());
+            if seen.insert(value) {
+                out.push(value);
+            }
+        }
+        out
+    }
+
+    /// Sorted values rotated at a random pivot, as in the rotated-array
+    /// search problems
+    pub fn rotated_sorted(&mut self, len: usize, range: RangeInclusive<i32>) -> Vec<i32> {
+        let mut nums = self.distinct_vec(len, range);
+        nums.sort_unstable();
+        if !nums.is_empty() {
+            let pivot = self.below(nums.len());
+            nums.rotate_left(pivot);
+        }
+        nums
+    }
+
+    /// Picks a random element, or `None` for an empty slice
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len())])
+        }
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Whether `nums` is a strictly ascending array rotated at some pivot
+pub fn is_rotated_sorted(nums: &[i32]) -> bool {
+    let descents = nums.windows(2).filter(|w| w[0] >= w[1]).count();
+    match (nums.first(), nums.last()) {
+        (Some(first), Some(last)) if descents == 1 => last < first,
+        _ => descents == 0,
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+/// Row/column offsets of the four edge-adjacent cells
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Row/column offsets of all eight surrounding cells
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Adjacency list for `n` nodes from LeetCode's `[[u,v],...]` edge list
+///
+/// # Panics
+///
+/// If an edge is shorter than `[u,v]` or names a node outside `0..n`;
+/// LeetCode's constraints guarantee neither happens.
+pub fn adjacency_list(n: usize, edges: &[Vec<i32>], directed: bool) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); n];
+    for edge in edges {
+        let (u, v) = endpoints(n, edge);
+        adjacency[u].push(v);
+        if !directed {
+            adjacency[v].push(u);
+        }
+    }
+    adjacency
+}
+
+/// Adjacency list with weights from a `[[u,v,w],...]` edge list
+///
+/// # Panics
+///
+/// Under the same conditions as [`adjacency_list`], or if an edge has no
+/// weight.
+pub fn weighted_adjacency_list(
+    n: usize,
+    edges: &[Vec<i32>],
+    directed: bool,
+) -> Vec<Vec<(usize, i32)>> {
+    let mut adjacency = vec![Vec::new(); n];
+    for edge in edges {
+        let (u, v) = endpoints(n, edge);
+        let w = *edge
+            .get(2)
+            .unwrap_or_else(|| panic!("edge {:?} has no weight", edge));
+        adjacency[u].push((v, w));
+        if !directed {
+            adjacency[v].push((u, w));
+        }
+    }
+    adjacency
+}
+
+/// Checked `(u, v)` node indexes of an edge in a graph of `n` nodes
+fn endpoints(n: usize, edge: &[i32]) -> (usize, usize) {
+    let node = |i: usize| {
+        edge.get(i)
+            .and_then(|&x| usize::try_from(x).ok())
+            .filter(|&x| x < n)
+            .unwrap_or_else(|| panic!("edge {:?} has no node {} in 0..{}", edge, i, n))
+    };
+    (node(0), node(1))
+}
+
+/// Cells reachable from `(row, col)` by one of `directions` that stay
+/// inside a `rows` × `cols` grid
+pub fn neighbors(
+    (rows, cols): (usize, usize),
+    (row, col): (usize, usize),
+    directions: &[(isize, isize)],
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    directions.iter().filter_map(move |&(dr, dc)| {
+        let r = row.checked_add_signed(dr)?;
+        let c = col.checked_add_signed(dc)?;
+        (r < rows && c < cols).then_some((r, c))
+    })
+}
+
+/// Dimensions of a grid as `(rows, cols)`
+pub fn dimensions<T>(grid: &[Vec<T>]) -> (usize, usize) {
+    (grid.len(), grid.first().map_or(0, Vec::len))
+}
+
+/// Draws a grid one row per line with aligned columns
+pub fn format_grid<T: Display>(grid: &[Vec<T>]) -> String {
+    let cells: Vec<Vec<String>> = grid
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect();
+    let width = cells.iter().flatten().map(String::len).max().unwrap_or(0);
+
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| format!("{:>width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency_list_links_both_ends_unless_directed() {
+        let edges = vec![vec![0, 1], vec![1, 2]];
+        assert_eq!(
+            adjacency_list(3, &edges, false),
+            vec![vec![1], vec![0, 2], vec![1]]
+        );
+        assert_eq!(
+            adjacency_list(3, &edges, true),
+            vec![vec![1], vec![2], vec![]]
+        );
+        assert_eq!(adjacency_list(2, &[], false), vec![Vec::<usize>::new(); 2]);
+    }
+
+    #[test]
+    fn weighted_adjacency_list_keeps_weights() {
+        let edges = vec![vec![0, 1, 5], vec![2, 0, 7]];
+        assert_eq!(
+            weighted_adjacency_list(3, &edges, false),
+            vec![vec![(1, 5), (2, 7)], vec![(0, 5)], vec![(0, 7)]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no node 1 in 0..2")]
+    fn adjacency_list_rejects_out_of_range_nodes() {
+        adjacency_list(2, &[vec![0, 2]], false);
+    }
+
+    #[test]
+    #[should_panic(expected = "no node 0 in 0..2")]
+    fn adjacency_list_rejects_negative_nodes() {
+        adjacency_list(2, &[vec![-1, 0]], false);
+    }
+
+    #[test]
+    #[should_panic(expected = "has no weight")]
+    fn weighted_adjacency_list_rejects_missing_weights() {
+        weighted_adjacency_list(2, &[vec![0, 1]], false);
+    }
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let corner: Vec<_> = neighbors((2, 3), (0, 0), &DIRECTIONS_4).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let middle: Vec<_> = neighbors((3, 3), (1, 1), &DIRECTIONS_4).collect();
+        assert_eq!(middle, vec![(0, 1), (1, 2), (2, 1), (1, 0)]);
+
+        let diagonal: Vec<_> = neighbors((2, 2), (1, 1), &DIRECTIONS_8).collect();
+        assert_eq!(diagonal, vec![(0, 0), (0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn grids_are_measured_and_drawn_aligned() {
+        let grid = vec![vec![1, 10], vec![100, 2]];
+        assert_eq!(dimensions(&grid), (2, 2));
+        assert_eq!(dimensions::<i32>(&[]), (0, 0));
+        assert_eq!(format_grid(&grid), "  1  10\n100   2");
+    }
+}
@@ -0,0 +1,621 @@
+use crate::modules::bench::{self, BenchConfig};
+use crate::modules::cases;
+use crate::modules::fuzz::{self, FuzzConfig, Shrink};
+use crate::modules::generate::Rng;
+use crate::modules::leetcode::{self, FromLeetCode, ToLeetCode};
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Widest input shown in the results table before truncating
+const INPUT_WIDTH: usize = 32;
+
+type ApproachFn<I, O> = Box<dyn Fn(I) -> O>;
+type GeneratorFn<I> = Box<dyn Fn(&mut Rng, usize) -> I>;
+type Oracle<I, O> = (ApproachFn<I, O>, fn(&I) -> Vec<I>);
+
+/// Runs the same test cases against every approach to a problem and
+/// reports correctness and timing side by side.
+///
+/// ```ignore
+/// Harness::new("Two Sum")
+///     .approach("hash map", |(nums, target)| Solution::two_sum(nums, target))
+///     .approach("brute force", |(nums, target)| brute_force::Solution::two_sum(nums, target))
+///     .case((vec![2, 7, 11, 15], 9), vec![0, 1])
+///     .example("nums = [3,2,4], target = 6", "[1,2]")
+///     .generator(|rng, n| (rng.vec(n, -1000..=1000), 0))
+///     .run();
+/// ```
+///
+/// Cases are also loaded from the problem's `.cases` file, if it has one.
+/// Running the bin with `--bench` times the approaches on inputs from the
+/// generator instead of checking the cases, and `--fuzz` compares them
+/// against the `.oracle(..)` reference implementation on random inputs,
+/// drawn from `.fuzz_generator(..)` when inputs that hit edge cases need
+/// a different shape than benchmark inputs.
+/// `--dump-cases` prints every case in `.cases` notation, `--cases PATH`
+/// runs only the cases in PATH, and `--judge` reports what the first
+/// approach returns on each case as a JSON line, for the local judge to
+/// compare against the expected outputs.
+pub struct Harness<I, O> {
+    title: String,
+    approaches: Vec<(String, ApproachFn<I, O>)>,
+    cases: Vec<(I, O)>,
+    normalize: Option<fn(O) -> O>,
+    generator: Option<GeneratorFn<I>>,
+    fuzz_generator: Option<GeneratorFn<I>>,
+    oracle: Option<Oracle<I, O>>,
+    constraint: Option<fn(&I) -> bool>,
+    /// `(approach, n)`: benchmark the approach only up to size `n`
+    bench_limits: Vec<(String, usize)>,
+}
+
+/// Outcome of one approach on one case
+#[derive(Debug, Clone)]
+pub struct Outcome<O> {
+    /// `None` when the approach panicked
+    pub output: Option<O>,
+    pub passed: bool,
+    pub elapsed: Duration,
+}
+
+/// Every outcome, indexed as `outcomes[case][approach]`
+pub struct Report<O> {
+    pub approaches: Vec<String>,
+    pub outcomes: Vec<Vec<Outcome<O>>>,
+}
+
+/// Results of a harness run collected by [`capture`] instead of printed
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub title: String,
+    pub cases: usize,
+    pub approaches: usize,
+    /// One message per failed case and approach
+    pub failures: Vec<String>,
+}
+
+/// What [`capture`] asked for and the result slot the harness fills in
+struct Capture {
+    bin_name: String,
+    /// Run only this case, by index
+    case: Option<usize>,
+    /// Benchmark the approaches instead of checking the cases
+    bench: Option<BenchConfig>,
+    summary: Option<Summary>,
+}
+
+thread_local! {
+    /// Set while [`capture`] runs a solution's `main`
+    static CAPTURE: RefCell<Option<Capture>> = const { RefCell::new(None) };
+}
+
+/// Runs a solution's `main` in this process; its harness records a
+/// [`Summary`] instead of printing and exiting. `None` when `main`
+/// doesn't run a harness.
+pub fn capture(bin_name: &str, main: fn()) -> Option<Summary> {
+    capture_with(bin_name, None, None, main)
+}
+
+/// Like [`capture`], but runs only the case at `index`
+pub fn capture_case(bin_name: &str, index: usize, main: fn()) -> Option<Summary> {
+    capture_with(bin_name, Some(index), None, main)
+}
+
+/// Runs a solution's `main` in this process with its harness
+/// benchmarking, as `--bench` would. The summary has no cases.
+pub fn capture_bench(bin_name: &str, config: &BenchConfig, main: fn()) -> Option<Summary> {
+    capture_with(bin_name, None, Some(config.clone()), main)
+}
+
+fn capture_with(
+    bin_name: &str,
+    case: Option<usize>,
+    bench: Option<BenchConfig>,
+    main: fn(),
+) -> Option<Summary> {
+    CAPTURE.with(|slot| {
+        *slot.borrow_mut() = Some(Capture {
+            bin_name: bin_name.to_string(),
+            case,
+            bench,
+            summary: None,
+        })
+    });
+    main();
+    CAPTURE
+        .with(|slot| slot.borrow_mut().take())
+        .and_then(|capture| capture.summary)
+}
+
+impl<O> Report<O> {
+    pub fn all_passed(&self) -> bool {
+        self.outcomes.iter().flatten().all(|o| o.passed)
+    }
+}
+
+impl<I, O> Harness<I, O>
+where
+    I: Clone + Debug + FromLeetCode + ToLeetCode,
+    O: Clone + PartialEq + Debug + FromLeetCode + ToLeetCode,
+{
+    pub fn new(title: &str) -> Self {
+        Harness {
+            title: title.to_string(),
+            approaches: Vec::new(),
+            cases: Vec::new(),
+            normalize: None,
+            generator: None,
+            fuzz_generator: None,
+            oracle: None,
+            constraint: None,
+            bench_limits: Vec::new(),
+        }
+    }
+
+    /// Adds a named approach; the first one added is the main solution
+    pub fn approach(mut self, name: &str, f: impl Fn(I) -> O + 'static) -> Self {
+        self.approaches.push((name.to_string(), Box::new(f)));
+        self
+    }
+
+    pub fn case(mut self, input: I, expected: O) -> Self {
+        self.cases.push((input, expected));
+        self
+    }
+
+    /// Adds a case pasted from a problem statement in LeetCode notation,
+    /// e.g. `.example("nums = [2,7,11,15], target = 9", "[0,1]")`
+    pub fn example(self, input: &str, expected: &str) -> Self {
+        let parsed = leetcode::parse(input)
+            .map_err(|e| format!("input `{}`: {}", input, e))
+            .and_then(|input| {
+                leetcode::parse(expected)
+                    .map(|expected| (input, expected))
+                    .map_err(|e| format!("expected `{}`: {}", expected, e))
+            });
+
+        match parsed {
+            Ok((input, expected)) => self.case(input, expected),
+            Err(e) => {
+                eprintln!("❌ {}: invalid example {}", self.title, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    pub fn cases(mut self, cases: impl IntoIterator<Item = (I, O)>) -> Self {
+        self.cases.extend(cases);
+        self
+    }
+
+    /// Canonicalizes outputs before comparing, for problems whose answer
+    /// may come back in any order
+    pub fn normalize(mut self, f: fn(O) -> O) -> Self {
+        self.normalize = Some(f);
+        self
+    }
+
+    /// Builds an input of size `n` for benchmarking
+    pub fn generator(mut self, f: impl Fn(&mut Rng, usize) -> I + 'static) -> Self {
+        self.generator = Some(Box::new(f));
+        self
+    }
+
+    /// Builds an input of size `n` for fuzzing in place of `.generator(..)`,
+    /// e.g. values from a small range so that duplicates show up
+    pub fn fuzz_generator(mut self, f: impl Fn(&mut Rng, usize) -> I + 'static) -> Self {
+        self.fuzz_generator = Some(Box::new(f));
+        self
+    }
+
+    /// Reference implementation the fuzzer trusts, typically brute force
+    pub fn oracle(mut self, f: impl Fn(I) -> O + 'static) -> Self
+    where
+        I: Shrink,
+    {
+        self.oracle = Some((Box::new(f), <I as Shrink>::shrink));
+        self
+    }
+
+    /// Precondition on inputs, e.g. "sorted then rotated", that generated
+    /// and shrunk fuzz inputs must satisfy
+    pub fn constraint(mut self, f: fn(&I) -> bool) -> Self {
+        self.constraint = Some(f);
+        self
+    }
+
+    /// Benchmarks the named approach only on sizes up to `max`, for one
+    /// that overflows or is too slow on larger inputs
+    pub fn bench_limit(mut self, approach: &str, max: usize) -> Self {
+        self.bench_limits.push((approach.to_string(), max));
+        self
+    }
+
+    /// Runs every case against every approach without printing
+    pub fn report(&self) -> Report<O> {
+        // Keep todo!() and index panics from flooding the output
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+
+        let outcomes = self
+            .cases
+            .iter()
+            .map(|(input, expected)| {
+                let expected = self.apply_normalize(Some(expected.clone()));
+                self.approaches
+                    .iter()
+                    .map(|(_, f)| self.run_one(f, input, expected.as_ref()))
+                    .collect()
+            })
+            .collect();
+
+        panic::set_hook(hook);
+
+        Report {
+            approaches: self
+                .approaches
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            outcomes,
+        }
+    }
+
+    /// Adds the cases stored in `path`, see [`cases::load`]
+    pub fn cases_file(self, path: &str) -> Result<Self, String> {
+        Ok(self.cases(cases::load(path)?))
+    }
+
+    /// Prints the results table and exits with status 1 on any failure,
+    /// or benchmarks the approaches when the bin was run with `--bench`
+    pub fn run(mut self) {
+        let captured = CAPTURE.with(|slot| {
+            slot.borrow().as_ref().map(|capture| {
+                (
+                    capture.bin_name.clone(),
+                    capture.case,
+                    capture.bench.clone(),
+                )
+            })
+        });
+        if let Some((bin_name, case, bench)) = captured {
+            let summary = match bench {
+                Some(config) => {
+                    self.bench(&config);
+                    Summary {
+                        title: self.title.clone(),
+                        cases: 0,
+                        approaches: self.approaches.len(),
+                        failures: Vec::new(),
+                    }
+                }
+                None => self.summarize(cases::path_for_bin(&bin_name), case),
+            };
+            CAPTURE.with(|slot| {
+                if let Some(capture) = slot.borrow_mut().as_mut() {
+                    capture.summary = Some(summary);
+                }
+            });
+            return;
+        }
+
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(config) = BenchConfig::from_args(&args) {
+            self.bench(&config);
+            return;
+        }
+        if let Some(config) = FuzzConfig::from_args(&args) {
+            if !self.fuzz(&config) {
+                std::process::exit(1);
+            }
+            return;
+        }
+
+        // A judge runs hidden cases: `--cases PATH` replaces every case
+        // with the ones in PATH, `--dump-cases` prints them all instead
+        let flag_value = |flag: &str| {
+            let i = args.iter().position(|a| a == flag)?;
+            args.get(i + 1).cloned()
+        };
+        if let Some(path) = flag_value("--cases") {
+            self.cases.clear();
+            self = match self.cases_file(&path) {
+                Ok(harness) => harness,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+        } else if let Some(path) = cases::path_for_current_bin() {
+            let before = self.cases.len();
+            self = match self.cases_file(&path) {
+                Ok(harness) => harness,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+            if self.cases.len() > before {
+                println!("📄 {} case(s) from {}", self.cases.len() - before, path);
+            }
+        }
+
+        if args.iter().any(|a| a == "--judge") {
+            self.judge();
+            return;
+        }
+        if args.iter().any(|a| a == "--dump-cases") {
+            // Normalized, so a judge can compare outputs as they are
+            for (input, expected) in &self.cases {
+                let expected = self
+                    .apply_normalize(Some(expected.clone()))
+                    .map(|e| e.to_value().to_string())
+                    .unwrap_or_default();
+                println!(
+                    "Input: {}\nOutput: {}\n",
+                    leetcode::format_args(input),
+                    expected
+                );
+            }
+            return;
+        }
+
+        let report = self.report();
+        self.print(&report);
+
+        if !report.all_passed() {
+            std::process::exit(1);
+        }
+    }
+
+    /// Runs every case, including those in `cases_path`, or only the one
+    /// at index `case`, without printing
+    fn summarize(mut self, cases_path: Option<String>, case: Option<usize>) -> Summary {
+        let mut failures = Vec::new();
+        if let Some(path) = cases_path {
+            match cases::load(&path) {
+                Ok(loaded) => self.cases.extend(loaded),
+                Err(e) => failures.push(e),
+            }
+        }
+
+        if let Some(index) = case {
+            if index < self.cases.len() {
+                self.cases = vec![self.cases.swap_remove(index)];
+            } else {
+                failures.push(format!(
+                    "❌ case {} no longer exists ({} cases), regenerate with: cargo run update --tests",
+                    index + 1,
+                    self.cases.len()
+                ));
+                self.cases.clear();
+            }
+        }
+
+        let report = self.report();
+        failures.extend(self.failure_messages(&report, case.unwrap_or(0)));
+        Summary {
+            title: self.title.clone(),
+            cases: self.cases.len(),
+            approaches: self.approaches.len(),
+            failures,
+        }
+    }
+
+    /// Runs the first approach on each case, printing one JSON line per
+    /// case as soon as it finishes so a judge that kills a slow run still
+    /// sees the earlier results. Only the normalized output is reported;
+    /// the judge decides whether it is right.
+    fn judge(&self) {
+        let Some((_, approach)) = self.approaches.first() else {
+            return;
+        };
+        panic::set_hook(Box::new(|_| {}));
+
+        for (i, (input, _)) in self.cases.iter().enumerate() {
+            let outcome = self.run_one(approach, input, None);
+            let line = serde_json::json!({
+                "case": i + 1,
+                "panicked": outcome.output.is_none(),
+                "elapsed_ns": outcome.elapsed.as_nanos() as u64,
+                "got": outcome.output.map(|o| o.to_value().to_string()),
+            });
+            println!("{}", line);
+        }
+    }
+
+    /// Times every approach on generated inputs of increasing size
+    pub fn bench(&self, config: &BenchConfig) {
+        let Some(generator) = &self.generator else {
+            eprintln!(
+                "❌ {} has no input generator; add .generator(..)",
+                self.title
+            );
+            std::process::exit(1);
+        };
+
+        let approaches: Vec<bench::Approach<I, O>> = self
+            .approaches
+            .iter()
+            .map(|(name, f)| bench::Approach {
+                name,
+                f: f.as_ref(),
+                max_size: self
+                    .bench_limits
+                    .iter()
+                    .find(|(approach, _)| approach == name)
+                    .map(|&(_, max)| max),
+            })
+            .collect();
+        let names: Vec<&str> = approaches.iter().map(|a| a.name).collect();
+
+        let samples = bench::run(&self.title, &approaches, generator.as_ref(), config);
+        bench::report(&samples, &names, config);
+    }
+
+    /// Differential testing of every approach against the oracle
+    pub fn fuzz(&self, config: &FuzzConfig) -> bool {
+        let generator = self.fuzz_generator.as_ref().or(self.generator.as_ref());
+        let (Some(generator), Some((oracle, shrink))) = (generator, &self.oracle) else {
+            eprintln!(
+                "❌ {} needs both .generator(..) and .oracle(..) to fuzz",
+                self.title
+            );
+            return false;
+        };
+
+        let target = fuzz::Target {
+            title: &self.title,
+            approaches: self
+                .approaches
+                .iter()
+                .map(|(name, f)| (name.as_str(), f.as_ref()))
+                .collect(),
+            oracle: oracle.as_ref(),
+            generator: generator.as_ref(),
+            shrink: *shrink,
+            constraint: self.constraint,
+            normalize: self.normalize,
+        };
+        fuzz::run(&target, config)
+    }
+
+    fn run_one(&self, f: &ApproachFn<I, O>, input: &I, expected: Option<&O>) -> Outcome<O> {
+        let input = input.clone();
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(input)));
+        let elapsed = start.elapsed();
+
+        let output = result.ok().and_then(|o| self.apply_normalize(Some(o)));
+        let passed = match (&output, expected) {
+            (Some(got), Some(expected)) => got == expected,
+            _ => false,
+        };
+
+        Outcome {
+            output,
+            passed,
+            elapsed,
+        }
+    }
+
+    fn apply_normalize(&self, output: Option<O>) -> Option<O> {
+        match self.normalize {
+            Some(f) => output.map(f),
+            None => output,
+        }
+    }
+
+    fn print(&self, report: &Report<O>) {
+        println!(
+            "🧪 {}: {} case(s) × {} approach(es)\n",
+            self.title,
+            self.cases.len(),
+            self.approaches.len()
+        );
+
+        let mut header = format!("{:<5} {:<width$}", "Case", "Input", width = INPUT_WIDTH);
+        for name in &report.approaches {
+            header.push_str(&format!("  {:<18}", name));
+        }
+        println!("{}", header.trim_end());
+
+        for (i, ((input, _), outcomes)) in self.cases.iter().zip(&report.outcomes).enumerate() {
+            let mut row = format!(
+                "{:<5} {:<width$}",
+                i + 1,
+                truncate(&format!("{:?}", input), INPUT_WIDTH),
+                width = INPUT_WIDTH
+            );
+            for outcome in outcomes {
+                let mark = if outcome.passed { "✅" } else { "❌" };
+                row.push_str(&format!(
+                    "  {} {:<15}",
+                    mark,
+                    format_elapsed(outcome.elapsed)
+                ));
+            }
+            println!("{}", row.trim_end());
+        }
+
+        let mut totals = format!("{:<5} {:<width$}", "Total", "", width = INPUT_WIDTH);
+        for a in 0..report.approaches.len() {
+            let passed = report.outcomes.iter().filter(|o| o[a].passed).count();
+            let elapsed: Duration = report.outcomes.iter().map(|o| o[a].elapsed).sum();
+            totals.push_str(&format!(
+                "  {:<18}",
+                format!(
+                    "{}/{} {}",
+                    passed,
+                    self.cases.len(),
+                    format_elapsed(elapsed)
+                )
+            ));
+        }
+        println!("{}", totals.trim_end());
+
+        self.print_failures(report);
+    }
+
+    fn print_failures(&self, report: &Report<O>) {
+        for failure in self.failure_messages(report, 0) {
+            println!("\n{}", failure);
+        }
+
+        if report.all_passed() {
+            println!("\n✅ All approaches passed!");
+        }
+    }
+
+    /// Numbers cases from `first_index`, for runs of a subset of cases
+    fn failure_messages(&self, report: &Report<O>, first_index: usize) -> Vec<String> {
+        let mut messages = Vec::new();
+        for (i, ((input, expected), outcomes)) in
+            self.cases.iter().zip(&report.outcomes).enumerate()
+        {
+            for (name, outcome) in report.approaches.iter().zip(outcomes) {
+                if outcome.passed {
+                    continue;
+                }
+                let got = match &outcome.output {
+                    Some(got) => format!("{:?}", got),
+                    None => "💥 panicked".to_string(),
+                };
+                messages.push(format!(
+                    "❌ {} failed case {}\n   Input:    {:?}\n   Expected: {:?}\n   Got:      {}",
+                    name,
+                    first_index + i + 1,
+                    input,
+                    expected,
+                    got
+                ));
+            }
+        }
+        messages
+    }
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(width - 1).collect();
+        format!("{}…", cut)
+    }
+}
+
+/// Formats a duration with a unit suited to its size
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let nanos = elapsed.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1_000.0)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1_000_000.0)
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HISTORY_DIR: &str = ".dsa";
+const HISTORY_PATH: &str = ".dsa/history.tsv";
+const MOCK_PATH: &str = ".dsa/mock.tsv";
+
+/// What kind of activity a history entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    /// A practice workspace was scaffolded for the problem
+    Start,
+    /// The problem was run through the runner
+    Run,
+    /// A practice attempt was checked against the original tests
+    Practice,
+    /// A problem was attempted in a timed mock interview
+    Mock,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Start => "start",
+            EventKind::Run => "run",
+            EventKind::Practice => "practice",
+            EventKind::Mock => "mock",
+        }
+    }
+
+    fn parse(value: &str) -> Option<EventKind> {
+        match value {
+            "start" => Some(EventKind::Start),
+            "run" => Some(EventKind::Run),
+            "practice" => Some(EventKind::Practice),
+            "mock" => Some(EventKind::Mock),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub bin_name: String,
+    pub kind: EventKind,
+    pub passed: bool,
+}
+
+/// Appends an event for `bin_name` to the history log
+pub fn record(bin_name: &str, kind: EventKind, passed: bool) -> Result<(), String> {
+    fs::create_dir_all(HISTORY_DIR)
+        .map_err(|e| format!("❌ Failed to create {}: {}", HISTORY_DIR, e))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)
+        .map_err(|e| format!("❌ Failed to open {}: {}", HISTORY_PATH, e))?;
+
+    let outcome = if passed { "pass" } else { "fail" };
+    writeln!(
+        file,
+        "{}\t{}\t{}\t{}",
+        now(),
+        bin_name,
+        kind.as_str(),
+        outcome
+    )
+    .map_err(|e| format!("❌ Failed to write {}: {}", HISTORY_PATH, e))
+}
+
+/// Loads every event from the history log, oldest first
+pub fn load() -> Vec<Event> {
+    let content = fs::read_to_string(HISTORY_PATH).unwrap_or_default();
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let timestamp = fields.next()?.parse().ok()?;
+            let bin_name = fields.next()?.to_string();
+            let kind = EventKind::parse(fields.next()?)?;
+            let passed = fields.next()? == "pass";
+
+            Some(Event {
+                timestamp,
+                bin_name,
+                kind,
+                passed,
+            })
+        })
+        .collect()
+}
+
+/// How a problem ended in a mock interview
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockOutcome {
+    Solved,
+    /// Attempted but not passing when the time ran out or the session ended
+    Unsolved,
+    Skipped,
+}
+
+impl MockOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MockOutcome::Solved => "solved",
+            MockOutcome::Unsolved => "unsolved",
+            MockOutcome::Skipped => "skipped",
+        }
+    }
+
+    fn parse(value: &str) -> Option<MockOutcome> {
+        match value {
+            "solved" => Some(MockOutcome::Solved),
+            "unsolved" => Some(MockOutcome::Unsolved),
+            "skipped" => Some(MockOutcome::Skipped),
+            _ => None,
+        }
+    }
+}
+
+/// One problem's line on a mock interview scorecard
+#[derive(Debug, Clone)]
+pub struct MockResult {
+    /// Start of the session, in seconds since the Unix epoch; shared by
+    /// every problem of the session
+    pub session: u64,
+    pub bin_name: String,
+    pub difficulty: String,
+    pub outcome: MockOutcome,
+    /// Submissions made
+    pub attempts: usize,
+    /// Seconds spent on the problem
+    pub seconds: u64,
+}
+
+/// Appends a finished session's scorecard to the mock log and one
+/// [`EventKind::Mock`] event per attempted problem to the history
+pub fn record_mock(results: &[MockResult]) -> Result<(), String> {
+    fs::create_dir_all(HISTORY_DIR)
+        .map_err(|e| format!("❌ Failed to create {}: {}", HISTORY_DIR, e))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(MOCK_PATH)
+        .map_err(|e| format!("❌ Failed to open {}: {}", MOCK_PATH, e))?;
+
+    for result in results {
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            result.session,
+            result.bin_name,
+            result.difficulty,
+            result.outcome.as_str(),
+            result.attempts,
+            result.seconds
+        )
+        .map_err(|e| format!("❌ Failed to write {}: {}", MOCK_PATH, e))?;
+
+        if result.outcome != MockOutcome::Skipped {
+            record(
+                &result.bin_name,
+                EventKind::Mock,
+                result.outcome == MockOutcome::Solved,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Loads every mock interview result, oldest session first
+pub fn load_mock() -> Vec<MockResult> {
+    let content = fs::read_to_string(MOCK_PATH).unwrap_or_default();
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            Some(MockResult {
+                session: fields.next()?.parse().ok()?,
+                bin_name: fields.next()?.to_string(),
+                difficulty: fields.next()?.to_string(),
+                outcome: MockOutcome::parse(fields.next()?)?,
+                attempts: fields.next()?.parse().ok()?,
+                seconds: fields.next()?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Problems with a passing run, practice or mock attempt
+pub fn solved(events: &[Event]) -> HashSet<String> {
+    events
+        .iter()
+        .filter(|e| e.passed && e.kind != EventKind::Start)
+        .map(|e| e.bin_name.clone())
+        .collect()
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Days since the Unix epoch (UTC) for a timestamp
+pub fn day_of(timestamp: u64) -> u64 {
+    timestamp / 86_400
+}
+
+/// Formats a day number from [`day_of`] as `YYYY-MM-DD`
+pub fn format_day(day: u64) -> String {
+    // Howard Hinnant's civil_from_days, restricted to dates after 1970
+    let z = day + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + u64::from(m <= 2);
+
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
@@ -0,0 +1,85 @@
+use crate::modules::provider::{self, ProblemSpec};
+use crate::modules::{metadata, source};
+
+/// Difficulty labels as they appear on their own line in a copied page
+const DIFFICULTIES: &[&str] = &["Easy", "Medium", "Hard"];
+
+/// Builds a problem from text copied off a problem page: the
+/// `impl Solution` stub from the code editor plus any of the statement
+/// around it. Picks out
+///
+/// - the starter code, from `impl Solution` to its closing brace
+/// - every `Input:` / `Output:` pair, skipping explanations and the rest
+///   of the statement
+/// - the title from a leading "1. Two Sum" line, else from the method name
+/// - a difficulty line ("Easy", "Medium" or "Hard")
+///
+/// `//!` header lines, as in a solution file, override what is found.
+/// `name` overrides the bin name, which defaults to the method's name.
+pub fn parse_snippet(text: &str, name: Option<&str>) -> Result<ProblemSpec, String> {
+    let Some(&(start, end)) = source::solution_impls(text).first() else {
+        return Err("❌ No `impl Solution { .. }` block found in the snippet".to_string());
+    };
+    let starter = text[start..end].to_string();
+    let signature = source::solution_signatures(&starter)
+        .into_iter()
+        .next()
+        .ok_or("❌ The `impl Solution` block has no method")?;
+
+    let meta = metadata::parse(text);
+    let rest = format!("{}\n{}", &text[..start], &text[end..]);
+    let lines: Vec<&str> = rest.lines().map(str::trim).collect();
+
+    let title = meta
+        .title
+        .or_else(|| lines.iter().find_map(|line| numbered_title(line)))
+        .unwrap_or_else(|| provider::title_from_slug(&signature.name));
+    let difficulty = meta.difficulty.or_else(|| {
+        lines
+            .iter()
+            .find(|line| DIFFICULTIES.contains(line))
+            .map(|line| line.to_string())
+    });
+
+    Ok(ProblemSpec {
+        slug: name.unwrap_or(&signature.name).replace('-', "_"),
+        title,
+        difficulty,
+        link: meta.link,
+        description: meta.description,
+        starter,
+        examples: examples(&lines)?,
+    })
+}
+
+/// `Input:` / `Output:` pairs in order, ignoring every other line
+fn examples(lines: &[&str]) -> Result<Vec<(String, String)>, String> {
+    let mut examples = Vec::new();
+    let mut pending: Option<&str> = None;
+
+    for line in lines {
+        if let Some(input) = line.strip_prefix("Input:") {
+            if pending.is_some() {
+                return Err(format!("❌ Example without an Output: {}", line));
+            }
+            pending = Some(input.trim());
+        } else if let Some(output) = line.strip_prefix("Output:") {
+            let input = pending
+                .take()
+                .ok_or_else(|| format!("❌ Output without an Input: {}", line))?;
+            examples.push((input.to_string(), output.trim().to_string()));
+        }
+    }
+
+    match pending {
+        Some(input) => Err(format!("❌ Example without an Output: Input: {}", input)),
+        None => Ok(examples),
+    }
+}
+
+/// "121. Best Time to Buy and Sell Stock" -> "Best Time to Buy and Sell Stock"
+fn numbered_title(line: &str) -> Option<String> {
+    let (number, title) = line.split_once(". ")?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+        .then(|| title.trim().to_string())
+}
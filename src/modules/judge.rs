@@ -0,0 +1,851 @@
+use crate::modules::cases::{self, RawCase};
+use crate::modules::discovery::{self, Problem};
+use crate::modules::leetcode::{self, Value as Answer};
+use crate::modules::provider::{self, ProblemSource};
+use crate::modules::{diagnostics, runner, source};
+use serde_json::{Value, json};
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where `judge serve` listens and `submit` connects by default
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:7878";
+
+/// Hidden cases, one `<bin>.cases` file per problem, kept apart from the
+/// solutions so none of them show in a solution file
+pub const HIDDEN_DIR: &str = "judge";
+
+/// Scratch package the submitted source is compiled in
+const WORKSPACE: &str = ".dsa/judge";
+
+/// Bin name of the compiled submission
+const SUBMISSION_BIN: &str = "submission";
+
+/// Module the submitted code is placed in, inside the reference program
+const SUBMISSION_MODULE: &str = "submission";
+
+/// Floats within this of the expected value are accepted, as on LeetCode
+const FLOAT_TOLERANCE: f64 = 1e-5;
+
+/// Printed by Rust's allocator when an allocation fails, e.g. under the
+/// memory limit
+const ALLOCATION_FAILED: &str = "memory allocation of";
+
+/// Resource limits for one submission
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// Per case
+    pub time: Duration,
+    /// Address space of the whole run, in megabytes
+    pub memory_mb: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            time: Duration::from_secs(2),
+            memory_mb: 512,
+        }
+    }
+}
+
+/// Outcome of a submission, named as on LeetCode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    WrongAnswer,
+    TimeLimitExceeded,
+    MemoryLimitExceeded,
+    RuntimeError,
+    CompileError,
+}
+
+impl Verdict {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Verdict::Accepted => "AC",
+            Verdict::WrongAnswer => "WA",
+            Verdict::TimeLimitExceeded => "TLE",
+            Verdict::MemoryLimitExceeded => "MLE",
+            Verdict::RuntimeError => "RE",
+            Verdict::CompileError => "CE",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Accepted => "✅ Accepted",
+            Verdict::WrongAnswer => "❌ Wrong Answer",
+            Verdict::TimeLimitExceeded => "⏰ Time Limit Exceeded",
+            Verdict::MemoryLimitExceeded => "🧠 Memory Limit Exceeded",
+            Verdict::RuntimeError => "💥 Runtime Error",
+            Verdict::CompileError => "🔨 Compile Error",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Verdict> {
+        [
+            Verdict::Accepted,
+            Verdict::WrongAnswer,
+            Verdict::TimeLimitExceeded,
+            Verdict::MemoryLimitExceeded,
+            Verdict::RuntimeError,
+            Verdict::CompileError,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.code() == code)
+    }
+}
+
+/// The first case a submission did not pass
+#[derive(Debug, Clone)]
+pub struct FailingCase {
+    /// 1-based
+    pub index: usize,
+    pub input: String,
+    pub expected: Option<String>,
+    pub got: Option<String>,
+}
+
+/// The judge's answer to a submission
+#[derive(Debug, Clone)]
+pub struct Judgement {
+    pub verdict: Verdict,
+    pub passed: usize,
+    pub total: usize,
+    pub failing: Option<FailingCase>,
+    /// Slowest case
+    pub max_time: Duration,
+    /// Compiler errors or the tail of stderr
+    pub message: String,
+}
+
+impl Judgement {
+    fn new(verdict: Verdict, total: usize) -> Self {
+        Judgement {
+            verdict,
+            passed: 0,
+            total,
+            failing: None,
+            max_time: Duration::ZERO,
+            message: String::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "verdict": self.verdict.code(),
+            "passed": self.passed,
+            "total": self.total,
+            "failing": self.failing.as_ref().map(|case| json!({
+                "case": case.index,
+                "input": case.input,
+                "expected": case.expected,
+                "got": case.got,
+            })),
+            "max_time_ms": self.max_time.as_secs_f64() * 1000.0,
+            "message": self.message,
+        })
+    }
+
+    pub fn from_json(value: &Value) -> Option<Judgement> {
+        let text = |v: &Value| v.as_str().map(str::to_string);
+        let failing = &value["failing"];
+        Some(Judgement {
+            verdict: Verdict::from_code(value["verdict"].as_str()?)?,
+            passed: value["passed"].as_u64()? as usize,
+            total: value["total"].as_u64()? as usize,
+            failing: failing.is_object().then(|| FailingCase {
+                index: failing["case"].as_u64().unwrap_or(0) as usize,
+                input: text(&failing["input"]).unwrap_or_default(),
+                expected: text(&failing["expected"]),
+                got: text(&failing["got"]),
+            }),
+            max_time: Duration::from_secs_f64(
+                value["max_time_ms"].as_f64().unwrap_or(0.0) / 1000.0,
+            ),
+            message: text(&value["message"]).unwrap_or_default(),
+        })
+    }
+
+    pub fn print(&self) {
+        println!("\n{}  ({})", self.verdict.label(), self.verdict.code());
+        if self.verdict != Verdict::CompileError {
+            println!(
+                "   {}/{} cases passed, slowest {:.2}ms",
+                self.passed,
+                self.total,
+                self.max_time.as_secs_f64() * 1000.0
+            );
+        }
+        if let Some(case) = &self.failing {
+            println!("\n   Failing case {}", case.index);
+            println!("   Input:    {}", case.input);
+            if let Some(expected) = &case.expected {
+                println!("   Expected: {}", expected);
+            }
+            if let Some(got) = &case.got {
+                println!("   Got:      {}", got);
+            }
+        }
+        if !self.message.trim().is_empty() {
+            println!("\n{}", self.message.trim_end());
+        }
+    }
+}
+
+/// Runs the `impl Solution` in `submission` against the problem's hidden
+/// cases. The judge owns `fn main`: the submitted code is spliced into the
+/// reference solution's harness, which only reports outputs, and they are
+/// compared with the expected ones here. `Err` means the submission could
+/// not be judged at all, e.g. an unknown problem.
+pub fn judge(problem: &Problem, submission: &str, limits: &Limits) -> Result<Judgement, String> {
+    let reference = fs::read_to_string(&problem.path)
+        .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+    if !reference.contains("Harness::new") {
+        return Err(format!(
+            "❌ {} doesn't use the Harness, so the judge can't run it",
+            problem.bin_name
+        ));
+    }
+    let hidden = hidden_cases(problem)?;
+    let raw = cases::parse_file(&hidden)?;
+    let total = raw.len();
+
+    let program = match splice(&reference, submission) {
+        Ok(program) => program,
+        Err(message) => {
+            let mut judgement = Judgement::new(Verdict::CompileError, total);
+            judgement.message = message;
+            return Ok(judgement);
+        }
+    };
+
+    let workspace = Path::new(WORKSPACE);
+    let manifest = write_workspace(workspace, &program)?;
+    let cases_path = workspace.join(format!("{}.cases", problem.bin_name));
+    fs::write(&cases_path, hidden)
+        .map_err(|e| format!("❌ Failed to write {}: {}", cases_path.display(), e))?;
+
+    let build = runner::build_package_bins(Some(&manifest), &[SUBMISSION_BIN.to_string()], true)?;
+    let Some(artifact) = build.artifacts.first() else {
+        let mut judgement = Judgement::new(Verdict::CompileError, total);
+        judgement.message = diagnostics::render(&build.diagnostics_for(SUBMISSION_BIN));
+        return Ok(judgement);
+    };
+
+    let args = [
+        "--judge".to_string(),
+        "--cases".to_string(),
+        cases_path.display().to_string(),
+    ];
+    let run = run_limited(&artifact.executable, &args, limits, total)?;
+    Ok(verdict(run, limits, &raw))
+}
+
+/// Hidden cases of a problem in `.cases` notation, from
+/// `judge/<bin>.cases` or else the examples of its catalog entry
+pub fn hidden_cases(problem: &Problem) -> Result<String, String> {
+    let path = format!("{}/{}.{}", HIDDEN_DIR, problem.bin_name, cases::EXTENSION);
+    if let Ok(text) = fs::read_to_string(&path) {
+        return Ok(text);
+    }
+
+    let slug = problem.bin_name.replace('_', "-");
+    let catalog = provider::Catalog::new(provider::DEFAULT_CATALOG);
+    if let Ok(spec) = catalog.fetch(&slug)
+        && !spec.examples.is_empty()
+    {
+        return Ok(spec
+            .examples
+            .iter()
+            .map(|(input, output)| format!("Input: {}\nOutput: {}\n", input, output))
+            .collect::<Vec<_>>()
+            .join("\n"));
+    }
+
+    Err(format!(
+        "❌ No hidden cases for {}\n💡 Add them to {} in .cases notation; `cargo run --bin {} -- --dump-cases` prints the solution's own",
+        problem.bin_name, path, problem.bin_name
+    ))
+}
+
+/// The reference solution with its top-level `Solution` replaced by the
+/// submitted one. The submission goes into its own module, minus any
+/// `fn main`, so its helpers and imports can't clash with the reference's;
+/// approach modules and the harness in `fn main` stay as they are.
+fn splice(reference: &str, submission: &str) -> Result<String, String> {
+    if source::solution_impls(submission).is_empty() {
+        return Err("No `impl Solution { .. }` block in the submission".to_string());
+    }
+
+    let mut submitted = submission.to_string();
+    if let Some((_, end)) = source::main_body(&submitted) {
+        let start = submitted[..end].rfind("fn main()").unwrap_or(end);
+        submitted.replace_range(start..end, "");
+    }
+    // The harness in the parent module needs to name it
+    let mut submitted = without_solution_struct(&submitted);
+    submitted.push_str("\npub struct Solution;\n");
+
+    let mut program = reference.to_string();
+    let top_level = source::solution_impls(reference)
+        .into_iter()
+        .filter(|&(start, _)| reference[start..].starts_with("impl"));
+    for (start, end) in top_level.rev() {
+        program.replace_range(start..end, "");
+    }
+    let mut program = without_solution_struct(&program);
+
+    program.push_str(&format!(
+        "\nuse {module}::Solution;\n\n#[allow(dead_code, unused_imports)]\nmod {module} {{\n{}\n\nuse super::*;\n}}\n",
+        submitted,
+        module = SUBMISSION_MODULE
+    ));
+    Ok(program)
+}
+
+/// `src` without its top-level `struct Solution;` declaration
+fn without_solution_struct(src: &str) -> String {
+    src.lines()
+        .filter(|line| !matches!(*line, "struct Solution;" | "pub struct Solution;"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes a package that depends on this crate, with `source` as its bin.
+/// Returns the manifest path.
+fn write_workspace(workspace: &Path, source: &str) -> Result<std::path::PathBuf, String> {
+    let src_dir = workspace.join("src");
+    fs::create_dir_all(&src_dir)
+        .map_err(|e| format!("❌ Failed to create {}: {}", src_dir.display(), e))?;
+
+    let root = std::env::current_dir().map_err(|e| format!("❌ {}", e))?;
+    // The empty [workspace] keeps cargo from looking for one in parent dirs
+    let manifest = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.0.0\"\nedition = \"2024\"\n\n[dependencies]\ndsa = {{ path = {:?} }}\n\n[workspace]\n",
+        SUBMISSION_BIN,
+        root.display().to_string()
+    );
+    let manifest_path = workspace.join("Cargo.toml");
+    fs::write(&manifest_path, manifest)
+        .map_err(|e| format!("❌ Failed to write {}: {}", manifest_path.display(), e))?;
+
+    let main = src_dir.join("main.rs");
+    fs::write(&main, source)
+        .map_err(|e| format!("❌ Failed to write {}: {}", main.display(), e))?;
+    Ok(manifest_path)
+}
+
+/// What came out of a run under the limits
+struct Run {
+    stdout: String,
+    stderr: String,
+    success: bool,
+    timed_out: bool,
+}
+
+/// Runs the submission with a wall-clock limit of the per-case limit
+/// times the number of cases, and on Unix under `ulimit -v`
+fn run_limited(
+    executable: &Path,
+    args: &[String],
+    limits: &Limits,
+    total: usize,
+) -> Result<Run, String> {
+    let mut command = if cfg!(unix) {
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg(format!(
+                "ulimit -v {} && exec \"$0\" \"$@\"",
+                limits.memory_mb * 1024
+            ))
+            .arg(executable)
+            .args(args);
+        command
+    } else {
+        let mut command = Command::new(executable);
+        command.args(args);
+        command
+    };
+    // Aborts would otherwise fill the message with a backtrace
+    command.env("RUST_BACKTRACE", "0");
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("❌ Failed to run {}: {}", executable.display(), e))?;
+
+    // Drain the pipes on threads so a chatty submission can't block on them
+    let read = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut text);
+            }
+            text
+        })
+    };
+    let stdout = read(
+        child
+            .stdout
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+    let stderr = read(
+        child
+            .stderr
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+
+    let deadline = Instant::now() + limits.time * total.max(1) as u32 + Duration::from_secs(1);
+    let mut timed_out = false;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                timed_out = true;
+                break None;
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(10)),
+            Err(e) => return Err(format!("❌ Failed to wait for the submission: {}", e)),
+        }
+    };
+
+    Ok(Run {
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        success: status.is_some_and(|s| s.success()),
+        timed_out,
+    })
+}
+
+/// Grades the per-case lines printed by the harness's `--judge` mode
+/// against the expected outputs of the hidden cases. Lines that aren't
+/// the next case's report, such as the submission's own output, are
+/// skipped.
+fn verdict(run: Run, limits: &Limits, raw: &[RawCase]) -> Judgement {
+    let total = raw.len();
+    let mut judgement = Judgement::new(Verdict::Accepted, total);
+    let mut seen = 0;
+
+    for line in run.stdout.lines() {
+        let Some(case) = raw.get(seen) else {
+            break;
+        };
+        let Ok(report) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        if report["case"].as_u64() != Some(seen as u64 + 1) {
+            continue;
+        }
+        seen += 1;
+        let elapsed = Duration::from_nanos(report["elapsed_ns"].as_u64().unwrap_or(0));
+        judgement.max_time = judgement.max_time.max(elapsed);
+
+        let got = report["got"].as_str();
+        let verdict = if report["panicked"] != false {
+            Verdict::RuntimeError
+        } else if !got.is_some_and(|got| same_answer(got, &case.output)) {
+            Verdict::WrongAnswer
+        } else if elapsed > limits.time {
+            Verdict::TimeLimitExceeded
+        } else {
+            judgement.passed += 1;
+            continue;
+        };
+
+        judgement.verdict = verdict;
+        judgement.failing = Some(FailingCase {
+            index: seen,
+            input: case.input.clone(),
+            expected: Some(case.output.clone()),
+            got: got.map(str::to_string),
+        });
+        return judgement;
+    }
+
+    if seen == total && run.success {
+        return judgement;
+    }
+
+    // The run ended early: killed, aborted or crashed on case `seen + 1`
+    judgement.verdict = if run.timed_out {
+        Verdict::TimeLimitExceeded
+    } else if run.stderr.contains(ALLOCATION_FAILED) {
+        Verdict::MemoryLimitExceeded
+    } else {
+        Verdict::RuntimeError
+    };
+    if seen == 0 && run.success {
+        judgement.message = "The harness reported no cases".to_string();
+    } else {
+        judgement.message = tail(&run.stderr, 10);
+    }
+    judgement.failing = raw.get(seen).map(|case| FailingCase {
+        index: seen + 1,
+        input: case.input.clone(),
+        expected: Some(case.output.clone()),
+        got: None,
+    });
+    judgement
+}
+
+/// Whether `got` matches `expected`, both in LeetCode notation. Numbers
+/// compare by value with a tolerance for floats; text that doesn't parse
+/// has to match exactly.
+fn same_answer(got: &str, expected: &str) -> bool {
+    match (leetcode::parse_args(got), leetcode::parse_args(expected)) {
+        (Ok(got), Ok(expected)) => {
+            got.len() == expected.len()
+                && got
+                    .iter()
+                    .zip(&expected)
+                    .all(|((_, got), (_, expected))| same_value(got, expected))
+        }
+        _ => got.trim() == expected.trim(),
+    }
+}
+
+fn same_value(got: &Answer, expected: &Answer) -> bool {
+    match (got, expected) {
+        (Answer::List(got), Answer::List(expected)) => {
+            got.len() == expected.len()
+                && got
+                    .iter()
+                    .zip(expected)
+                    .all(|(got, expected)| same_value(got, expected))
+        }
+        (Answer::Float(_) | Answer::Int(_), Answer::Float(_))
+        | (Answer::Float(_), Answer::Int(_)) => {
+            let number = |value: &Answer| match value {
+                Answer::Int(n) => *n as f64,
+                Answer::Float(x) => *x,
+                _ => f64::NAN,
+            };
+            (number(got) - number(expected)).abs() <= FLOAT_TOLERANCE
+        }
+        _ => got == expected,
+    }
+}
+
+fn tail(text: &str, lines: usize) -> String {
+    let all: Vec<&str> = text.lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+/// Serves `POST /submit` with a JSON body `{"problem": .., "source": ..}`
+/// until interrupted, judging one submission at a time
+pub fn serve(address: &str, limits: &Limits) -> Result<(), String> {
+    let server = tiny_http::Server::http(address)
+        .map_err(|e| format!("❌ Failed to listen on {}: {}", address, e))?;
+    println!("⚖️  Judge listening on http://{}", address);
+    println!(
+        "   Limits: {}ms per case, {}MB. Ctrl-C to stop",
+        limits.time.as_millis(),
+        limits.memory_mb
+    );
+
+    respond(&server, |body| handle_submission(body, limits));
+    Ok(())
+}
+
+/// Answers requests to `server` until it is unblocked, grading each
+/// `POST /submit` body with `handle`
+pub fn respond(server: &tiny_http::Server, handle: impl Fn(&str) -> Result<Judgement, String>) {
+    for mut request in server.incoming_requests() {
+        let (status, body) =
+            if request.method() != &tiny_http::Method::Post || request.url() != "/submit" {
+                (404, json!({ "error": "POST /submit" }))
+            } else {
+                let mut text = String::new();
+                let _ = request.as_reader().read_to_string(&mut text);
+                match handle(&text) {
+                    Ok(judgement) => (200, judgement.to_json()),
+                    Err(e) => (400, json!({ "error": e })),
+                }
+            };
+
+        let response = tiny_http::Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(
+                "Content-Type: application/json"
+                    .parse::<tiny_http::Header>()
+                    .expect("valid header"),
+            );
+        if let Err(e) = request.respond(response) {
+            eprintln!("❌ Failed to respond: {}", e);
+        }
+    }
+}
+
+fn handle_submission(body: &str, limits: &Limits) -> Result<Judgement, String> {
+    let request: Value =
+        serde_json::from_str(body).map_err(|e| format!("❌ Invalid JSON body: {}", e))?;
+    let (Some(name), Some(source)) = (request["problem"].as_str(), request["source"].as_str())
+    else {
+        return Err("❌ Expected {\"problem\": .., \"source\": ..}".to_string());
+    };
+
+    let problems = discovery::discover_problems();
+    let problem = problems
+        .iter()
+        .find(|p| p.bin_name == name)
+        .ok_or_else(|| format!("❌ Problem '{}' not found", name))?;
+
+    println!("📥 Judging {}...", name);
+    let judgement = judge(problem, source, limits)?;
+    println!(
+        "📤 {}: {} ({}/{})",
+        name,
+        judgement.verdict.code(),
+        judgement.passed,
+        judgement.total
+    );
+    Ok(judgement)
+}
+
+/// Sends a submission to a judge at `address` and waits for the verdict
+pub fn submit(address: &str, problem: &str, source: &str) -> Result<Judgement, String> {
+    let body = json!({ "problem": problem, "source": source }).to_string();
+    let mut stream = TcpStream::connect(address).map_err(|e| {
+        format!(
+            "❌ No judge at {}: {}\n💡 Start one with: cargo run judge serve",
+            address, e
+        )
+    })?;
+
+    write!(
+        stream,
+        "POST /submit HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        address,
+        body.len(),
+        body
+    )
+    .map_err(|e| format!("❌ Failed to send the submission: {}", e))?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| format!("❌ Failed to read the verdict: {}", e))?;
+
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("❌ Malformed response from the judge")?;
+    let value: Value = serde_json::from_str(body.trim())
+        .map_err(|e| format!("❌ Malformed verdict from the judge: {}", e))?;
+    if let Some(error) = value["error"].as_str() {
+        return Err(error.to_string());
+    }
+    Judgement::from_json(&value).ok_or_else(|| "❌ Malformed verdict from the judge".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_cases() -> Vec<RawCase> {
+        [("[1,2]", "3"), ("[4,5]", "9"), ("[0.5,0.25]", "0.75")]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (input, output))| RawCase {
+                line: i * 3 + 1,
+                input: input.to_string(),
+                output: output.to_string(),
+            })
+            .collect()
+    }
+
+    fn report(case: usize, got: &str) -> String {
+        json!({ "case": case, "panicked": false, "elapsed_ns": 1000, "got": got }).to_string()
+    }
+
+    fn run(lines: &[String], stderr: &str, success: bool, timed_out: bool) -> Run {
+        Run {
+            stdout: lines.join("\n"),
+            stderr: stderr.to_string(),
+            success,
+            timed_out,
+        }
+    }
+
+    #[test]
+    fn verdicts() {
+        let panicked = json!({ "case": 2, "panicked": true, "elapsed_ns": 10, "got": null });
+        let slow =
+            json!({ "case": 2, "panicked": false, "elapsed_ns": 5_000_000_000u64, "got": "9" });
+        let all = || vec![report(1, "3"), report(2, "9"), report(3, "0.750001")];
+
+        // (name, run, verdict, passed, failing case)
+        let table = [
+            (
+                "accepted",
+                run(&all(), "", true, false),
+                Verdict::Accepted,
+                3,
+                None,
+            ),
+            (
+                "wrong answer",
+                run(&[report(1, "3"), report(2, "8")], "", true, false),
+                Verdict::WrongAnswer,
+                1,
+                Some(2),
+            ),
+            (
+                "panic",
+                run(&[report(1, "3"), panicked.to_string()], "", true, false),
+                Verdict::RuntimeError,
+                1,
+                Some(2),
+            ),
+            (
+                "slow case",
+                run(&[report(1, "3"), slow.to_string()], "", true, false),
+                Verdict::TimeLimitExceeded,
+                1,
+                Some(2),
+            ),
+            (
+                "killed at the deadline",
+                run(&[report(1, "3")], "", false, true),
+                Verdict::TimeLimitExceeded,
+                1,
+                Some(2),
+            ),
+            (
+                "allocation failure",
+                run(
+                    &[report(1, "3")],
+                    "memory allocation of 4294967296 bytes failed\n",
+                    false,
+                    false,
+                ),
+                Verdict::MemoryLimitExceeded,
+                1,
+                Some(2),
+            ),
+            (
+                "partial output then crash",
+                run(
+                    &[report(1, "3"), report(2, "9")],
+                    "thread 'main' panicked",
+                    false,
+                    false,
+                ),
+                Verdict::RuntimeError,
+                2,
+                Some(3),
+            ),
+            (
+                "zero lines with success",
+                run(&[], "", true, false),
+                Verdict::RuntimeError,
+                0,
+                Some(1),
+            ),
+            (
+                "stray and out-of-order lines are skipped",
+                run(
+                    &[
+                        "debug output".to_string(),
+                        report(2, "0"),
+                        report(1, "3"),
+                        report(2, "9"),
+                        report(3, "0.75"),
+                    ],
+                    "",
+                    true,
+                    false,
+                ),
+                Verdict::Accepted,
+                3,
+                None,
+            ),
+        ];
+
+        let limits = Limits::default();
+        for (name, run, verdict, passed, failing) in table {
+            let judgement = super::verdict(run, &limits, &raw_cases());
+            assert_eq!(judgement.verdict, verdict, "{}", name);
+            assert_eq!(judgement.passed, passed, "{}", name);
+            assert_eq!(
+                judgement.failing.map(|case| case.index),
+                failing,
+                "{}",
+                name
+            );
+        }
+    }
+
+    fn problem(bin_name: &str) -> Problem {
+        Problem {
+            name: bin_name.to_string(),
+            category: "01-arrays".to_string(),
+            bin_name: bin_name.to_string(),
+            path: format!("01-arrays/{}.rs", bin_name),
+        }
+    }
+
+    #[test]
+    fn hidden_cases_fall_back_to_the_catalog_entry() {
+        let hidden = hidden_cases(&problem("missing_number")).unwrap();
+        let raw = cases::parse_file(&hidden).unwrap();
+        assert_eq!(raw.len(), 3);
+        assert_eq!(
+            (raw[0].input.as_str(), raw[0].output.as_str()),
+            ("nums = [3,0,1]", "2")
+        );
+
+        let err = hidden_cases(&problem("no_such_problem")).unwrap_err();
+        assert!(err.contains("judge/no_such_problem.cases"), "{}", err);
+    }
+
+    #[test]
+    fn failing_case_shows_the_hidden_case() {
+        let run = run(&[report(1, "4")], "", true, false);
+        let judgement = verdict(run, &Limits::default(), &raw_cases());
+        let failing = judgement.failing.unwrap();
+
+        assert_eq!(failing.input, "[1,2]");
+        assert_eq!(failing.expected.as_deref(), Some("3"));
+        assert_eq!(failing.got.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn answers_compare_by_value() {
+        assert!(same_answer("[1, 2]", "[1,2]"));
+        assert!(same_answer("0.333333", "0.33333"));
+        assert!(same_answer("2.00000", "2"));
+        assert!(same_answer("2.0", "2.00000"));
+        assert!(same_answer("0.30000000000000004", "0.3"));
+        assert!(!same_answer("0.34", "0.33"));
+        assert!(!same_answer("[1,2]", "[2,1]"));
+        assert!(!same_answer("\"1\"", "1"));
+    }
+
+    #[test]
+    fn splices_a_snippet_into_the_reference() {
+        let reference = "use dsa::modules::harness::Harness;\n\nstruct Solution;\n\nimpl Solution {\n    pub fn f(x: i32) -> i32 {\n        x\n    }\n}\n\nmod brute_force {\n    pub struct Solution;\n\n    impl Solution {\n        pub fn f(x: i32) -> i32 {\n            x\n        }\n    }\n}\n\nfn main() {\n    Harness::new(\"F\").approach(\"main\", |(x,)| Solution::f(x)).run();\n}\n";
+        let snippet = "impl Solution {\n    pub fn f(x: i32) -> i32 {\n        x + 1\n    }\n}\n\nfn main() {}\n";
+
+        let program = splice(reference, snippet).unwrap();
+        assert!(!program.contains("        x\n    }\n}\n\nmod brute_force"));
+        assert!(program.contains("mod brute_force"));
+        assert!(program.contains("x + 1"));
+        assert!(program.contains("use submission::Solution;"));
+        assert_eq!(program.matches("fn main()").count(), 1);
+        assert_eq!(program.matches("\nstruct Solution;").count(), 0);
+
+        assert!(splice(reference, "fn main() {}").is_err());
+    }
+}
@@ -0,0 +1,650 @@
+use std::fmt;
+
+/// A value written in LeetCode's example notation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// Short name used in type errors
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Int(_) => "integer",
+            Value::Float(_) => "number",
+            Value::Str(_) => "string",
+            Value::List(_) => "list",
+        }
+    }
+}
+
+/// Prints the compact form LeetCode uses: `[1,null,"a"]`. Floats use the
+/// shortest form that parses back to the same value, and strings use the
+/// JSON escapes the parser accepts.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(x) => write!(f, "{:?}", x),
+            Value::Str(s) => write_string(f, s),
+            Value::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Writes `s` quoted, escaping quotes, backslashes and control characters
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Converts a parsed [`Value`] into a Rust type
+///
+/// ```ignore
+/// let (nums, target): (Vec<i32>, i32) = parse("nums = [2,7,11,15], target = 9")?;
+/// let grid: Vec<Vec<char>> = parse(r#"[["1","0"],["0","1"]]"#)?;
+/// ```
+pub trait FromLeetCode: Sized {
+    /// Whether `[]` means `None` for `Option<Self>`, as LeetCode writes an
+    /// empty linked list or tree
+    const EMPTY_IS_NONE: bool = false;
+
+    fn from_value(value: &Value) -> Result<Self, String>;
+
+    /// Builds the value from a whole argument list; tuples take one
+    /// argument per field, everything else exactly one argument
+    fn from_args(args: &[Value]) -> Result<Self, String> {
+        match args {
+            [value] => Self::from_value(value),
+            _ => Err(format!("expected 1 argument, found {}", args.len())),
+        }
+    }
+}
+
+/// Parses example text such as `nums = [2,7,11,15], target = 9` or
+/// `[[1,2],[3]]` into `T`
+pub fn parse<T: FromLeetCode>(text: &str) -> Result<T, String> {
+    let args: Vec<Value> = parse_args(text)?
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect();
+    T::from_args(&args)
+}
+
+/// Splits example text into its arguments, keeping the names when given.
+/// Arguments are separated by commas or newlines, as in LeetCode's
+/// problem statements and its console input respectively.
+pub fn parse_args(text: &str) -> Result<Vec<(Option<String>, Value)>, String> {
+    let mut parser = Parser::new(text);
+    let mut args = Vec::new();
+
+    loop {
+        parser.skip_separators();
+        if parser.at_end() {
+            break;
+        }
+        let name = parser.argument_name();
+        args.push((name, parser.value()?));
+    }
+    Ok(args)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Parser { text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == ',' || c.is_whitespace() {
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Consumes `name =` if present
+    fn argument_name(&mut self) -> Option<String> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let after = rest[len..].trim_start();
+        if !after.starts_with('=') || matches!(&rest[..len], "true" | "false" | "null") {
+            return None;
+        }
+        let name = rest[..len].to_string();
+        self.pos = self.text.len() - after.len() + 1;
+        self.skip_whitespace();
+        Some(name)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('[') => self.list(),
+            Some('"') => self.string(),
+            Some(c) if c == '-' || c == '+' || c.is_ascii_digit() => self.number(),
+            Some(_) => self.keyword(),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn list(&mut self) -> Result<Value, String> {
+        self.pos += 1;
+        let mut items = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                return Ok(Value::List(items));
+            }
+            if !items.is_empty() {
+                if self.peek() != Some(',') {
+                    return Err(self.error("expected ',' or ']'"));
+                }
+                self.pos += 1;
+            }
+            items.push(self.value()?);
+        }
+    }
+
+    fn string(&mut self) -> Result<Value, String> {
+        self.pos += 1;
+        let mut out = String::new();
+        let mut chars = self.rest().char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(Value::Str(out));
+                }
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => out.push('\n'),
+                    Some((_, 't')) => out.push('\t'),
+                    Some((_, 'r')) => out.push('\r'),
+                    Some((_, 'b')) => out.push('\u{8}'),
+                    Some((_, 'f')) => out.push('\u{c}'),
+                    Some((_, '0')) => out.push('\0'),
+                    Some((j, 'u')) => {
+                        let start = self.pos + j + 1;
+                        let decoded = self
+                            .text
+                            .get(start..start + 4)
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32);
+                        let Some(decoded) = decoded else {
+                            self.pos = start;
+                            return Err(self.error("expected 4 hex digits after \\u"));
+                        };
+                        out.push(decoded);
+                        for _ in 0..4 {
+                            chars.next();
+                        }
+                    }
+                    Some((_, escaped)) => out.push(escaped),
+                    None => break,
+                },
+                _ => out.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(i, c)| {
+                !(c.is_ascii_digit()
+                    || c == '.'
+                    || c == 'e'
+                    || c == 'E'
+                    || ((c == '-' || c == '+') && (i == 0 || rest[..i].ends_with(['e', 'E']))))
+            })
+            .map_or(rest.len(), |(i, _)| i);
+        let literal = &rest[..len];
+        self.pos += len;
+
+        if let Ok(n) = literal.parse::<i64>() {
+            Ok(Value::Int(n))
+        } else {
+            literal
+                .parse::<f64>()
+                .map(Value::Float)
+                .map_err(|_| format!("invalid number '{}'", literal))
+        }
+    }
+
+    fn keyword(&mut self) -> Result<Value, String> {
+        for (word, value) in [
+            ("null", Value::Null),
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+        ] {
+            if self.rest().starts_with(word) {
+                self.pos += word.len();
+                return Ok(value);
+            }
+        }
+        Err(self.error("expected a value"))
+    }
+
+    fn error(&self, message: &str) -> String {
+        let near: String = self.rest().chars().take(12).collect();
+        if near.is_empty() {
+            format!("{} at end of input", message)
+        } else {
+            format!("{} near '{}'", message, near)
+        }
+    }
+}
+
+fn mismatch<T>(expected: &str, found: &Value) -> Result<T, String> {
+    Err(format!(
+        "expected {}, found {} {}",
+        expected,
+        found.kind(),
+        found
+    ))
+}
+
+macro_rules! impl_from_leetcode_int {
+    ($($ty:ty),+) => {
+        $(
+            impl FromLeetCode for $ty {
+                fn from_value(value: &Value) -> Result<Self, String> {
+                    match value {
+                        Value::Int(n) => <$ty>::try_from(*n)
+                            .map_err(|_| format!("{} is out of range for {}", n, stringify!($ty))),
+                        _ => mismatch("integer", value),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_leetcode_int!(i32, i64, u32, u64, usize);
+
+impl FromLeetCode for f64 {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Int(n) => Ok(*n as f64),
+            Value::Float(x) => Ok(*x),
+            _ => mismatch("number", value),
+        }
+    }
+}
+
+impl FromLeetCode for bool {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            _ => mismatch("boolean", value),
+        }
+    }
+}
+
+impl FromLeetCode for String {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Str(s) => Ok(s.clone()),
+            _ => mismatch("string", value),
+        }
+    }
+}
+
+/// Boards like Sudoku write each cell as a one-character string
+impl FromLeetCode for char {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        let mut chars = match value {
+            Value::Str(s) => s.chars(),
+            _ => return mismatch("character", value),
+        };
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => mismatch("single character", value),
+        }
+    }
+}
+
+impl<T: FromLeetCode> FromLeetCode for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::List(items) => items.iter().map(T::from_value).collect(),
+            _ => mismatch("list", value),
+        }
+    }
+}
+
+/// `null` entries, as in level-order tree serializations like `[1,null,2]`
+impl<T: FromLeetCode> FromLeetCode for Option<T> {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(None),
+            Value::List(items) if T::EMPTY_IS_NONE && items.is_empty() => Ok(None),
+            _ => T::from_value(value).map(Some),
+        }
+    }
+}
+
+macro_rules! impl_from_leetcode_tuple {
+    ($len:expr; $($name:ident $index:tt),+) => {
+        impl<$($name: FromLeetCode),+> FromLeetCode for ($($name,)+) {
+            fn from_value(value: &Value) -> Result<Self, String> {
+                match value {
+                    Value::List(items) => Self::from_args(items),
+                    _ => mismatch("list", value),
+                }
+            }
+
+            fn from_args(args: &[Value]) -> Result<Self, String> {
+                if args.len() != $len {
+                    return Err(format!("expected {} argument(s), found {}", $len, args.len()));
+                }
+                Ok(($(
+                    $name::from_value(&args[$index])
+                        .map_err(|e| format!("argument {}: {}", $index + 1, e))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_from_leetcode_tuple!(1; A 0);
+impl_from_leetcode_tuple!(2; A 0, B 1);
+impl_from_leetcode_tuple!(3; A 0, B 1, C 2);
+impl_from_leetcode_tuple!(4; A 0, B 1, C 2, D 3);
+
+/// Converts a Rust value back into LeetCode notation, the inverse of
+/// [`FromLeetCode`]
+pub trait ToLeetCode {
+    /// See [`FromLeetCode::EMPTY_IS_NONE`]
+    const EMPTY_IS_NONE: bool = false;
+
+    fn to_value(&self) -> Value;
+
+    /// Splits the value into arguments; only tuples have more than one
+    fn to_args(&self) -> Vec<Value> {
+        vec![self.to_value()]
+    }
+}
+
+/// Formats arguments the way LeetCode's examples do: `[1,2], 3`
+pub fn format_args<T: ToLeetCode>(value: &T) -> String {
+    value
+        .to_args()
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+macro_rules! impl_to_leetcode_int {
+    ($($ty:ty),+) => {
+        $(
+            impl ToLeetCode for $ty {
+                fn to_value(&self) -> Value {
+                    Value::Int(*self as i64)
+                }
+            }
+        )+
+    };
+}
+
+impl_to_leetcode_int!(i32, i64, u32, u64, usize);
+
+impl ToLeetCode for f64 {
+    fn to_value(&self) -> Value {
+        Value::Float(*self)
+    }
+}
+
+impl ToLeetCode for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl ToLeetCode for String {
+    fn to_value(&self) -> Value {
+        Value::Str(self.clone())
+    }
+}
+
+impl ToLeetCode for char {
+    fn to_value(&self) -> Value {
+        Value::Str(self.to_string())
+    }
+}
+
+impl<T: ToLeetCode> ToLeetCode for Vec<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(T::to_value).collect())
+    }
+}
+
+impl<T: ToLeetCode> ToLeetCode for Option<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None if T::EMPTY_IS_NONE => Value::List(Vec::new()),
+            None => Value::Null,
+        }
+    }
+}
+
+macro_rules! impl_to_leetcode_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: ToLeetCode),+> ToLeetCode for ($($name,)+) {
+            fn to_value(&self) -> Value {
+                Value::List(self.to_args())
+            }
+
+            fn to_args(&self) -> Vec<Value> {
+                vec![$(self.$index.to_value()),+]
+            }
+        }
+    };
+}
+
+impl_to_leetcode_tuple!(A 0);
+impl_to_leetcode_tuple!(A 0, B 1);
+impl_to_leetcode_tuple!(A 0, B 1, C 2);
+impl_to_leetcode_tuple!(A 0, B 1, C 2, D 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_named_arguments() {
+        let (nums, target): (Vec<i32>, i32) = parse("nums = [2,7,11,15], target = 9").unwrap();
+        assert_eq!(nums, vec![2, 7, 11, 15]);
+        assert_eq!(target, 9);
+
+        let args = parse_args("[1,2]\n3").unwrap();
+        assert_eq!(
+            args,
+            vec![
+                (None, Value::List(vec![Value::Int(1), Value::Int(2)])),
+                (None, Value::Int(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_nested_lists_and_null() {
+        let grid: Vec<Vec<i32>> = parse("[[1,2],[],[3]]").unwrap();
+        assert_eq!(grid, vec![vec![1, 2], vec![], vec![3]]);
+
+        let tree: Vec<Option<i32>> = parse("root = [1,null,2]").unwrap();
+        assert_eq!(tree, vec![Some(1), None, Some(2)]);
+    }
+
+    #[test]
+    fn parses_strings_with_escapes() {
+        let board: Vec<Vec<char>> = parse(r#"[["1","."],[".","2"]]"#).unwrap();
+        assert_eq!(board, vec![vec!['1', '.'], vec!['.', '2']]);
+
+        let s: String = parse(r#"s = "say \"hi\"\\n\tend""#).unwrap();
+        assert_eq!(s, "say \"hi\"\\n\tend");
+    }
+
+    #[test]
+    fn parses_negative_numbers_and_floats() {
+        let args = parse_args("-5, 2.5, -0.25, 1e3").unwrap();
+        let values: Vec<Value> = args.into_iter().map(|(_, value)| value).collect();
+        assert_eq!(
+            values,
+            vec![
+                Value::Int(-5),
+                Value::Float(2.5),
+                Value::Float(-0.25),
+                Value::Float(1000.0),
+            ]
+        );
+
+        let x: f64 = parse("x = 2").unwrap();
+        assert_eq!(x, 2.0);
+    }
+
+    #[test]
+    fn display_round_trips_through_the_parser() {
+        let values = vec![
+            Value::Float(0.1 + 0.2),
+            Value::Float(2.0),
+            Value::Float(-1e-7),
+            Value::Float(1e21),
+            Value::Float(1.0 / 3.0),
+            Value::Str("quote \" slash \\ tab\t".to_string()),
+            Value::Str("cr\r lf\n nul\0 bell\u{7} del\u{7f}".to_string()),
+            Value::Str("ünïcode ✓".to_string()),
+            Value::List(vec![Value::Null, Value::Int(-3), Value::Bool(true)]),
+        ];
+        for value in values {
+            let text = value.to_string();
+            let parsed: Vec<Value> = parse_args(&text)
+                .unwrap()
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect();
+            assert_eq!(parsed, vec![value], "{}", text);
+        }
+    }
+
+    #[test]
+    fn displays_json_escapes() {
+        assert_eq!(Value::Str("a\0b".to_string()).to_string(), r#""a\u0000b""#);
+        assert_eq!(Value::Float(2.0).to_string(), "2.0");
+
+        let s: String = parse(r#""A\r\b""#).unwrap();
+        assert_eq!(s, "A\r\u{8}");
+        assert!(
+            parse_args(r#""\u00g1""#)
+                .unwrap_err()
+                .contains("4 hex digits")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for text in ["[1,2", "[1 2]", r#""abc"#, "nums = ", "foo", "1.2.3"] {
+            assert!(parse_args(text).is_err(), "parsed {:?}", text);
+        }
+
+        assert!(
+            parse_args("[1 2]")
+                .unwrap_err()
+                .contains("expected ',' or ']'")
+        );
+        assert!(
+            parse_args(r#""abc"#)
+                .unwrap_err()
+                .contains("unterminated string")
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_types() {
+        let err = parse::<Vec<i32>>(r#"[1,"a"]"#).unwrap_err();
+        assert!(err.contains("expected integer, found string"), "{}", err);
+
+        assert!(parse::<i32>("3000000000").is_err());
+        assert!(parse::<char>(r#""ab""#).is_err());
+        assert!(parse::<(Vec<i32>, i32)>("[1,2]").is_err());
+    }
+
+    #[test]
+    fn format_args_round_trips() {
+        let input = (vec![-1, 0, 3], "a \"b\"".to_string(), vec![Some(1), None]);
+        let text = format_args(&input);
+        assert_eq!(text, r#"[-1,0,3], "a \"b\"", [1,null]"#);
+
+        let parsed: (Vec<i32>, String, Vec<Option<i32>>) = parse(&text).unwrap();
+        assert_eq!(parsed, input);
+
+        let floats = (vec![0.5, -2.25],);
+        let parsed: (Vec<f64>,) = parse(&format_args(&floats)).unwrap();
+        assert_eq!(parsed, floats);
+    }
+}
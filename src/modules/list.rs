@@ -0,0 +1,119 @@
+use crate::modules::fuzz::Shrink;
+use crate::modules::leetcode::{FromLeetCode, ToLeetCode, Value};
+use std::fmt;
+
+/// LeetCode's singly-linked list node, as used in `Option<Box<ListNode>>`
+/// signatures. Lists are written `[1,2,3]` in examples and `[]` is `None`.
+#[derive(PartialEq, Eq, Clone)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+}
+
+/// Builds a list from its values, `None` when empty
+pub fn from_vec(values: &[i32]) -> Option<Box<ListNode>> {
+    let mut head = None;
+    for &val in values.iter().rev() {
+        head = Some(Box::new(ListNode { val, next: head }));
+    }
+    head
+}
+
+/// Collects the values of a list in order
+pub fn to_vec(head: &Option<Box<ListNode>>) -> Vec<i32> {
+    let mut values = Vec::new();
+    let mut node = head.as_deref();
+    while let Some(current) = node {
+        values.push(current.val);
+        node = current.next.as_deref();
+    }
+    values
+}
+
+/// Draws a list as `1 -> 2 -> 3`, or `(empty)`
+pub fn format_list(head: &Option<Box<ListNode>>) -> String {
+    let values = to_vec(head);
+    if values.is_empty() {
+        return "(empty)".to_string();
+    }
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Shows the values as a vector so harness tables stay on one line
+impl fmt::Debug for ListNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut node = Some(self);
+        while let Some(current) = node {
+            list.entry(&current.val);
+            node = current.next.as_deref();
+        }
+        list.finish()
+    }
+}
+
+impl FromLeetCode for Box<ListNode> {
+    const EMPTY_IS_NONE: bool = true;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        let values = Vec::<i32>::from_value(value)?;
+        from_vec(&values).ok_or_else(|| "expected a non-empty list".to_string())
+    }
+}
+
+impl ToLeetCode for Box<ListNode> {
+    const EMPTY_IS_NONE: bool = true;
+
+    fn to_value(&self) -> Value {
+        to_vec(&Some(self.clone())).to_value()
+    }
+}
+
+impl Shrink for Box<ListNode> {
+    fn shrink(&self) -> Vec<Self> {
+        to_vec(&Some(self.clone()))
+            .shrink()
+            .iter()
+            .filter_map(|values| from_vec(values))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_list_is_none() {
+        assert_eq!(from_vec(&[]), None);
+        assert!(to_vec(&None).is_empty());
+        assert_eq!(format_list(&None), "(empty)");
+    }
+
+    #[test]
+    fn single_node_round_trips() {
+        let head = from_vec(&[4]);
+        assert_eq!(head, Some(Box::new(ListNode::new(4))));
+        assert_eq!(to_vec(&head), vec![4]);
+        assert_eq!(format_list(&head), "4");
+    }
+
+    #[test]
+    fn values_keep_their_order() {
+        let head = from_vec(&[1, 2, 3]);
+        assert_eq!(to_vec(&head), vec![1, 2, 3]);
+        assert_eq!(format_list(&head), "1 -> 2 -> 3");
+        assert_eq!(format!("{:?}", head.unwrap()), "[1, 2, 3]");
+    }
+}
@@ -0,0 +1,104 @@
+use crate::modules::discovery::Problem;
+use std::fs;
+
+/// Problem details declared in a solution's `//!` header, e.g.
+///
+/// ```text
+/// //! title: 3Sum
+/// //! link: https://leetcode.com/problems/3sum/
+/// //! difficulty: Medium
+/// //! description: Find all unique triplets in `nums` that sum to zero.
+/// //! time: O(n²)
+/// //! space: O(1)
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Metadata {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub difficulty: Option<String>,
+    /// One-line problem statement
+    pub description: Option<String>,
+    /// Time complexity, e.g. "O(n)"
+    pub time: Option<String>,
+    /// Space complexity, e.g. "O(1)"
+    pub space: Option<String>,
+}
+
+impl Metadata {
+    /// Difficulty label, or "Unknown" when the header omits it
+    pub fn difficulty_label(&self) -> &str {
+        self.difficulty.as_deref().unwrap_or("Unknown")
+    }
+
+    /// Declared title, falling back to the problem's display name
+    pub fn title_or<'a>(&'a self, problem: &'a Problem) -> &'a str {
+        self.title.as_deref().unwrap_or(&problem.name)
+    }
+}
+
+/// Reads the metadata header of a problem's solution file
+pub fn load(problem: &Problem) -> Metadata {
+    fs::read_to_string(&problem.path)
+        .map(|content| parse(&content))
+        .unwrap_or_default()
+}
+
+/// Parses the leading `//! key: value` lines of a source file
+pub fn parse(content: &str) -> Metadata {
+    let mut metadata = Metadata::default();
+
+    for line in content.lines() {
+        let Some(entry) = line.trim().strip_prefix("//!") else {
+            break;
+        };
+        let Some((key, value)) = entry.split_once(':') else {
+            continue;
+        };
+
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let value = Some(value.to_string());
+        match key.trim() {
+            "title" => metadata.title = value,
+            "link" => metadata.link = value,
+            "difficulty" => metadata.difficulty = value,
+            "description" => metadata.description = value,
+            "time" => metadata.time = value,
+            "space" => metadata.space = value,
+            _ => {}
+        }
+    }
+
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_leading_header_only() {
+        let meta = parse(
+            "//! title: 3Sum\n//! link: https://leetcode.com/problems/3sum/\n//! difficulty: Medium\n//! time:  O(n²) \n//! space:\n//! unknown: ignored\n//! no separator\n\n//! description: after the header\n",
+        );
+
+        assert_eq!(meta.title.as_deref(), Some("3Sum"));
+        assert_eq!(
+            meta.link.as_deref(),
+            Some("https://leetcode.com/problems/3sum/")
+        );
+        assert_eq!(meta.difficulty_label(), "Medium");
+        assert_eq!(meta.time.as_deref(), Some("O(n²)"));
+        assert_eq!(meta.space, None);
+        assert_eq!(meta.description, None);
+    }
+
+    #[test]
+    fn missing_header_is_empty() {
+        let meta = parse("use std::fs;\n//! title: too late\n");
+        assert_eq!(meta.title, None);
+        assert_eq!(meta.difficulty_label(), "Unknown");
+    }
+}
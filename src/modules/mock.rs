@@ -0,0 +1,296 @@
+use crate::modules::discovery::{self, Problem};
+use crate::modules::generate::Rng;
+use crate::modules::history::{self, MockOutcome, MockResult};
+use crate::modules::practice::{self, PRACTICE_BIN, PRACTICE_PATH};
+use crate::modules::{judge, metadata, pick, runner, stats};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where each problem's hidden cases are kept during a session
+const HIDDEN_DIR: &str = ".dsa/mock";
+
+/// A warning is printed when this much time is left
+const WARNING: Duration = Duration::from_secs(5 * 60);
+
+/// Settings for a mock interview
+#[derive(Debug, Clone)]
+pub struct MockConfig {
+    /// Number of problems
+    pub count: usize,
+    /// Time limit for the whole session
+    pub minutes: u64,
+    /// Difficulty of each problem in turn, repeated when `count` is larger
+    pub mix: Vec<String>,
+    /// Only pick from categories matching this
+    pub category: Option<String>,
+    /// Overwrite the attempt already in the practice workspace
+    pub reset: bool,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        MockConfig {
+            count: 3,
+            minutes: 45,
+            mix: vec![
+                "easy".to_string(),
+                "medium".to_string(),
+                "medium".to_string(),
+            ],
+            category: None,
+            reset: false,
+        }
+    }
+}
+
+/// Picks `config.count` distinct problems following the difficulty mix.
+/// When a difficulty runs out, any remaining problem is used instead.
+pub fn choose(problems: &[Problem], config: &MockConfig, rng: &mut Rng) -> Vec<Problem> {
+    let filter = pick::Filter {
+        category: config.category.clone(),
+        ..Default::default()
+    };
+    let mut pool = pick::candidates(problems, &filter);
+    let mut chosen = Vec::new();
+
+    for i in 0..config.count {
+        if pool.is_empty() {
+            break;
+        }
+        let wanted = config.mix.get(i % config.mix.len().max(1));
+        let matching: Vec<usize> = (0..pool.len())
+            .filter(|&j| {
+                wanted.is_none_or(|difficulty| {
+                    metadata::load(&pool[j])
+                        .difficulty_label()
+                        .eq_ignore_ascii_case(difficulty)
+                })
+            })
+            .collect();
+
+        let index = match rng.pick(&matching) {
+            Some(&j) => j,
+            None => rng.below(pool.len()),
+        };
+        chosen.push(pool.swap_remove(index));
+    }
+    chosen
+}
+
+/// Runs an interactive session: each problem is scaffolded into the
+/// practice workspace with only its examples visible, and `submit` runs
+/// the full set of cases against the attempt. Ends with a scorecard that
+/// is stored in the history.
+pub fn run(config: &MockConfig) -> Result<Vec<MockResult>, String> {
+    if let Some(current) = practice::current_problem().filter(|_| !config.reset) {
+        return Err(format!(
+            "⚠️  {} already holds an attempt at '{}'\n💡 Use --reset to overwrite it",
+            PRACTICE_PATH, current
+        ));
+    }
+
+    let problems = discovery::discover_problems();
+    let chosen = choose(&problems, config, &mut Rng::from_time());
+    if chosen.is_empty() {
+        return Err("❌ No problems match the mock interview settings".to_string());
+    }
+
+    fs::create_dir_all(HIDDEN_DIR)
+        .map_err(|e| format!("❌ Failed to create {}: {}", HIDDEN_DIR, e))?;
+
+    let limit = Duration::from_secs(config.minutes * 60);
+    let session = history::now();
+    let deadline = Instant::now() + limit;
+    start_timer(limit);
+
+    println!(
+        "\n🎤 Mock interview: {} problem(s) in {} minutes",
+        chosen.len(),
+        config.minutes
+    );
+    println!("   Commands: [s]ubmit, s[k]ip, [t]ime, [q]uit");
+
+    // Whatever was played is scored even when a problem fails to set up
+    let mut results = Vec::new();
+    let played = interview(&chosen, session, deadline, &mut results);
+    let _ = fs::remove_dir_all(HIDDEN_DIR);
+    if !results.is_empty() {
+        print_scorecard(&results, limit);
+        history::record_mock(&results)?;
+    }
+    played.map(|_| results)
+}
+
+/// Plays `chosen` in order, pushing each problem's result as it ends
+fn interview(
+    chosen: &[Problem],
+    session: u64,
+    deadline: Instant,
+    results: &mut Vec<MockResult>,
+) -> Result<(), String> {
+    let mut stdin = io::stdin().lock();
+    let mut ended = false;
+
+    for (i, problem) in chosen.iter().enumerate() {
+        let difficulty = metadata::load(problem).difficulty_label().to_string();
+        let mut result = MockResult {
+            session,
+            bin_name: problem.bin_name.clone(),
+            difficulty,
+            outcome: MockOutcome::Skipped,
+            attempts: 0,
+            seconds: 0,
+        };
+        if ended {
+            results.push(result);
+            continue;
+        }
+
+        // Solutions checked with plain asserts have no cases to hide
+        let mut submit_args = Vec::new();
+        if uses_harness(problem) {
+            match judge::hidden_cases(problem) {
+                Ok(cases) => {
+                    let hidden = format!("{}/{}.cases", HIDDEN_DIR, problem.bin_name);
+                    fs::write(&hidden, cases)
+                        .map_err(|e| format!("❌ Failed to write {}: {}", hidden, e))?;
+                    submit_args = vec!["--cases".to_string(), hidden];
+                }
+                Err(e) => println!("{}\n⚠️  Submitting runs only the visible cases", e),
+            }
+        }
+        practice::scaffold_interview(problem)?;
+
+        println!("\n{}", "=".repeat(50));
+        println!("📝 Problem {} of {}\n", i + 1, chosen.len());
+        pick::print(problem);
+        println!("\n✏️  Solve it in {}", PRACTICE_PATH);
+
+        let started = Instant::now();
+        result.outcome = MockOutcome::Unsolved;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                println!("\n⏰ Time's up!");
+                ended = true;
+                break;
+            }
+
+            print!("\n[{} left] > ", format_clock(remaining));
+            io::stdout().flush().ok();
+            let mut line = String::new();
+            if stdin.read_line(&mut line).unwrap_or(0) == 0 {
+                ended = true;
+                break;
+            }
+            // The prompt may have waited past the deadline
+            if Instant::now() >= deadline {
+                println!("\n⏰ Time's up!");
+                ended = true;
+                break;
+            }
+
+            match line.trim() {
+                "s" | "submit" => {
+                    result.attempts += 1;
+                    if runner::run_bin_with(PRACTICE_BIN, false, &submit_args) {
+                        result.outcome = MockOutcome::Solved;
+                        break;
+                    }
+                    println!("💡 Keep going, or [k] to skip");
+                }
+                "k" | "skip" => {
+                    result.outcome = if result.attempts > 0 {
+                        MockOutcome::Unsolved
+                    } else {
+                        MockOutcome::Skipped
+                    };
+                    break;
+                }
+                "t" | "time" => {
+                    println!(
+                        "⏱️  {} on this problem, {} left",
+                        format_clock(started.elapsed()),
+                        format_clock(remaining)
+                    );
+                }
+                "q" | "quit" => {
+                    ended = true;
+                    break;
+                }
+                "" => {}
+                other => println!("❓ Unknown command '{}'", other),
+            }
+        }
+
+        result.seconds = started.elapsed().as_secs();
+        if result.outcome == MockOutcome::Unsolved && result.attempts == 0 && ended {
+            result.outcome = MockOutcome::Skipped;
+        }
+        results.push(result);
+    }
+    Ok(())
+}
+
+fn uses_harness(problem: &Problem) -> bool {
+    fs::read_to_string(&problem.path).is_ok_and(|src| src.contains("Harness::new"))
+}
+
+/// Prints warnings from a background thread as the deadline approaches
+fn start_timer(limit: Duration) {
+    thread::spawn(move || {
+        if limit > WARNING {
+            thread::sleep(limit - WARNING);
+            println!("\n⏰ {} minutes left!", WARNING.as_secs() / 60);
+            thread::sleep(WARNING);
+        } else {
+            thread::sleep(limit);
+        }
+        println!("\n⏰ Time's up! Press Enter for the scorecard");
+    });
+}
+
+fn print_scorecard(results: &[MockResult], limit: Duration) {
+    println!("\n🏁 Scorecard\n");
+    println!(
+        "   {:<30} {:<10} {:<12} {:>8} {:>8}",
+        "Problem", "Difficulty", "Result", "Attempts", "Time"
+    );
+
+    for result in results {
+        let outcome = match result.outcome {
+            MockOutcome::Solved => "✅ solved",
+            MockOutcome::Unsolved => "❌ unsolved",
+            MockOutcome::Skipped => "⏭️  skipped",
+        };
+        println!(
+            "   {:<30} {:<10} {:<12} {:>8} {:>8}",
+            result.bin_name,
+            result.difficulty,
+            outcome,
+            result.attempts,
+            format_clock(Duration::from_secs(result.seconds))
+        );
+    }
+
+    let solved = results
+        .iter()
+        .filter(|r| r.outcome == MockOutcome::Solved)
+        .count();
+    let total: u64 = results.iter().map(|r| r.seconds).sum();
+    println!(
+        "\n   Score: {}/{} solved in {} of {}",
+        solved,
+        results.len(),
+        stats::format_duration(total),
+        stats::format_duration(limit.as_secs())
+    );
+}
+
+/// `mm:ss`
+fn format_clock(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
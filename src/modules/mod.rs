@@ -1,5 +1,33 @@
 // Module declarations
+pub mod bench;
+pub mod cases;
+pub mod diagnostics;
 pub mod discovery;
+pub mod drift;
+pub mod editor;
+pub mod export;
+pub mod fuzz;
 pub mod fzf;
+pub mod generate;
+pub mod graph;
+pub mod harness;
+pub mod history;
+pub mod import;
+pub mod judge;
+pub mod leetcode;
+pub mod list;
+pub mod metadata;
+pub mod mock;
+pub mod notes;
+pub mod pick;
+pub mod practice;
+pub mod provider;
+pub mod readme;
 pub mod runner;
+pub mod source;
+pub mod stats;
+pub mod template;
+pub mod testgen;
+pub mod tree;
 pub mod updater;
+pub mod watch;
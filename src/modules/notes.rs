@@ -0,0 +1,182 @@
+use crate::modules::discovery::Problem;
+use crate::modules::metadata::{self, Metadata};
+use std::fs;
+use std::io::Write;
+
+/// Extension of the notes file stored next to each solution
+pub const EXTENSION: &str = "md";
+
+/// A problem's notes: Markdown with optional `---` front matter holding
+/// complexity fields, e.g.
+///
+/// ```text
+/// ---
+/// time: O(n)
+/// space: O(n)
+/// time.brute force: O(n²)
+/// ---
+///
+/// ## Intuition
+/// Store each complement in a map while scanning.
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Notes {
+    /// Time complexity of the main approach
+    pub time: Option<String>,
+    pub space: Option<String>,
+    /// `(approach, time)` from `time.<approach>` fields, keyed by the
+    /// harness approach name
+    pub approach_times: Vec<(String, String)>,
+    /// Markdown after the front matter
+    pub body: String,
+}
+
+impl Notes {
+    /// First line of prose in the body, skipping headings
+    pub fn summary(&self) -> Option<&str> {
+        self.body
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+    }
+
+    /// Time complexity from the notes, else from the solution header
+    pub fn time_or<'a>(&'a self, meta: &'a Metadata) -> Option<&'a str> {
+        self.time.as_deref().or(meta.time.as_deref())
+    }
+
+    /// Space complexity from the notes, else from the solution header
+    pub fn space_or<'a>(&'a self, meta: &'a Metadata) -> Option<&'a str> {
+        self.space.as_deref().or(meta.space.as_deref())
+    }
+}
+
+/// Reads a problem's notes file, if it has one
+pub fn load(problem: &Problem) -> Option<Notes> {
+    fs::read_to_string(problem.sidecar_path(EXTENSION))
+        .ok()
+        .map(|text| parse(&text))
+}
+
+/// Splits off and reads the front matter; text without it is all body
+pub fn parse(text: &str) -> Notes {
+    let mut notes = Notes::default();
+
+    let mut lines = text.lines();
+    let mut front = Vec::new();
+    let mut closed = false;
+    if lines.next().map(str::trim) == Some("---") {
+        for line in lines.by_ref() {
+            if line.trim() == "---" {
+                closed = true;
+                break;
+            }
+            front.push(line);
+        }
+    }
+    if !closed {
+        notes.body = text.trim().to_string();
+        return notes;
+    }
+    notes.body = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    for line in front {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim() {
+            "time" => notes.time = Some(value),
+            "space" => notes.space = Some(value),
+            key => {
+                if let Some(approach) = key.strip_prefix("time.") {
+                    notes
+                        .approach_times
+                        .push((approach.trim().to_string(), value));
+                }
+            }
+        }
+    }
+    notes
+}
+
+/// Starting point for a new notes file, with the complexity from the
+/// solution header filled in
+pub fn template(problem: &Problem) -> String {
+    let meta = metadata::load(problem);
+    format!(
+        "---\ntime: {}\nspace: {}\n---\n\n# {}\n\n## Intuition\n\n## Pitfalls\n",
+        meta.time.as_deref().unwrap_or(""),
+        meta.space.as_deref().unwrap_or(""),
+        meta.title_or(problem)
+    )
+}
+
+/// Creates the notes file from the template unless it exists. Returns its
+/// path.
+pub fn create(problem: &Problem) -> Result<String, String> {
+    let path = problem.sidecar_path(EXTENSION);
+    if !fs::exists(&path).unwrap_or(false) {
+        fs::write(&path, template(problem))
+            .map_err(|e| format!("❌ Failed to write {}: {}", path, e))?;
+        println!("📝 Created {}", path);
+    }
+    Ok(path)
+}
+
+/// Adds a paragraph to the end of the notes, creating them if needed
+pub fn append(problem: &Problem, text: &str) -> Result<(), String> {
+    let path = create(problem)?;
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("❌ Failed to open {}: {}", path, e))?;
+    writeln!(file, "\n{}", text.trim()).map_err(|e| format!("❌ Failed to write {}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_front_matter_and_approach_times() {
+        let notes = parse(
+            "---\ntime: O(n)\nspace:\ntime.brute force: O(n²)\ntime.sort :O(n log n)\nnot a field\n---\n\n## Intuition\nStore each complement.\n",
+        );
+
+        assert_eq!(notes.time.as_deref(), Some("O(n)"));
+        assert_eq!(notes.space, None);
+        assert_eq!(
+            notes.approach_times,
+            vec![
+                ("brute force".to_string(), "O(n²)".to_string()),
+                ("sort".to_string(), "O(n log n)".to_string()),
+            ]
+        );
+        assert_eq!(notes.body, "## Intuition\nStore each complement.");
+        assert_eq!(notes.summary(), Some("Store each complement."));
+    }
+
+    #[test]
+    fn text_without_closed_front_matter_is_all_body() {
+        let unclosed = parse("---\ntime: O(n)\n\nNotes");
+        assert_eq!(unclosed.time, None);
+        assert_eq!(unclosed.body, "---\ntime: O(n)\n\nNotes");
+
+        let plain = parse("# Title\n\n");
+        assert_eq!(plain.body, "# Title");
+        assert_eq!(plain.summary(), None);
+    }
+
+    #[test]
+    fn complexity_falls_back_to_the_header() {
+        let notes = parse("---\nspace: O(1)\n---\n");
+        let meta = metadata::parse("//! time: O(n)\n//! space: O(n)\n");
+
+        assert_eq!(notes.time_or(&meta), Some("O(n)"));
+        assert_eq!(notes.space_or(&meta), Some("O(1)"));
+    }
+}
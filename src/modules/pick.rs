@@ -0,0 +1,170 @@
+use crate::modules::discovery::Problem;
+use crate::modules::generate::Rng;
+use crate::modules::{history, metadata};
+
+/// Narrows the problems [`random`] chooses from
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    /// Substring of the category directory, e.g. "arrays"
+    pub category: Option<String>,
+    /// Difficulty label, case-insensitive, e.g. "medium"
+    pub difficulty: Option<String>,
+    /// Skip problems with a passing run or practice in the history
+    pub unsolved: bool,
+}
+
+/// Problems matching every part of `filter`
+pub fn candidates(problems: &[Problem], filter: &Filter) -> Vec<Problem> {
+    let solved = if filter.unsolved {
+        history::solved(&history::load())
+    } else {
+        Default::default()
+    };
+
+    problems
+        .iter()
+        .filter(|p| {
+            filter
+                .category
+                .as_ref()
+                .is_none_or(|category| p.category.to_lowercase().contains(&category.to_lowercase()))
+        })
+        .filter(|p| {
+            filter.difficulty.as_ref().is_none_or(|difficulty| {
+                metadata::load(p)
+                    .difficulty_label()
+                    .eq_ignore_ascii_case(difficulty)
+            })
+        })
+        .filter(|p| !solved.contains(&p.bin_name))
+        .cloned()
+        .collect()
+}
+
+/// A random problem matching `filter`
+pub fn random(problems: &[Problem], filter: &Filter, rng: &mut Rng) -> Option<Problem> {
+    rng.pick(&candidates(problems, filter)).cloned()
+}
+
+/// The problem for a day from [`history::day_of`]: the same for everyone
+/// with the same set of problems
+pub fn daily(problems: &[Problem], day: u64) -> Option<Problem> {
+    let mut sorted = problems.to_vec();
+    sorted.sort_by(|a, b| a.bin_name.cmp(&b.bin_name));
+
+    let mut rng = Rng::new(day_seed(day));
+    rng.pick(&sorted).cloned()
+}
+
+/// Spreads consecutive days apart. The multiplier differs from the
+/// constant `Rng::new` XORs with, which would give day 1 a zero state.
+fn day_seed(day: u64) -> u64 {
+    day.wrapping_mul(0xBF58_476D_1CE4_E5B9)
+}
+
+/// Title, difficulty and link of a picked problem, without spoilers
+pub fn print(problem: &Problem) {
+    let meta = metadata::load(problem);
+
+    println!("🎲 {}", meta.title_or(problem));
+    println!(
+        "   {} · {} · {}",
+        problem.category_label(),
+        meta.difficulty_label(),
+        problem.path
+    );
+    if let Some(description) = &meta.description {
+        println!("   {}", description);
+    }
+    if let Some(link) = &meta.link {
+        println!("   🔗 {}", link);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::discovery;
+
+    fn problem(category: &str, bin_name: &str) -> Problem {
+        Problem {
+            name: bin_name.to_string(),
+            category: category.to_string(),
+            bin_name: bin_name.to_string(),
+            path: format!("{}/{}.rs", category, bin_name),
+        }
+    }
+
+    fn sample() -> Vec<Problem> {
+        ["a", "b", "c", "d", "e", "f"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                problem(
+                    if i % 2 == 0 {
+                        "01-arrays"
+                    } else {
+                        "02-strings"
+                    },
+                    name,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn daily_is_stable_for_a_day_and_varies_across_days() {
+        let problems = sample();
+        let mut reversed = problems.clone();
+        reversed.reverse();
+
+        let mut picks = std::collections::HashSet::new();
+        for day in 0..30 {
+            let pick = daily(&problems, day).unwrap().bin_name;
+            assert_eq!(daily(&problems, day).unwrap().bin_name, pick);
+            assert_eq!(daily(&reversed, day).unwrap().bin_name, pick);
+            picks.insert(pick);
+        }
+        assert!(picks.len() > 3, "{:?}", picks);
+        assert!(daily(&[], 1).is_none());
+    }
+
+    #[test]
+    fn no_day_seeds_a_zero_state() {
+        // A zero state yields 0 forever, always picking the first problem
+        for day in 0..10_000 {
+            assert_ne!(Rng::new(day_seed(day)).next_u64(), 0, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn candidates_filter_by_category() {
+        let filter = Filter {
+            category: Some("STRINGS".to_string()),
+            ..Default::default()
+        };
+        let names: Vec<String> = candidates(&sample(), &filter)
+            .into_iter()
+            .map(|p| p.bin_name)
+            .collect();
+        assert_eq!(names, vec!["b", "d", "f"]);
+        assert_eq!(candidates(&sample(), &Filter::default()).len(), 6);
+    }
+
+    #[test]
+    fn candidates_filter_by_difficulty_from_the_header() {
+        let problems = discovery::discover_problems();
+        let filter = Filter {
+            difficulty: Some("easy".to_string()),
+            ..Default::default()
+        };
+        let easy = candidates(&problems, &filter);
+
+        assert!(!easy.is_empty());
+        assert!(easy.len() < problems.len());
+        assert!(
+            easy.iter()
+                .all(|p| metadata::load(p).difficulty_label() == "Easy")
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::modules::discovery::Problem;
+use crate::modules::history::{self, EventKind};
+use crate::modules::{runner, source};
+use std::fs;
+
+/// Bin target that holds the current practice attempt
+pub const PRACTICE_BIN: &str = "practice";
+pub const PRACTICE_PATH: &str = "practice/practice.rs";
+
+/// First-line marker recording which problem the workspace belongs to
+const HEADER_PREFIX: &str = "// practice: ";
+
+/// Copies `problem` into the practice workspace with every
+/// `impl Solution` method body replaced by `todo!()`
+pub fn scaffold(problem: &Problem, reset: bool) -> Result<(), String> {
+    if let Some(current) = current_problem().filter(|_| !reset) {
+        return Err(format!(
+            "⚠️  {} already holds an attempt at '{}'\n💡 Use --reset to overwrite it",
+            PRACTICE_PATH, current
+        ));
+    }
+
+    let original = fs::read_to_string(&problem.path)
+        .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+    write_workspace(problem, &blank_solution(&original))?;
+
+    println!("✏️  Practice workspace ready: {}", PRACTICE_PATH);
+    println!("💡 Fill in the todo!() bodies, then run:");
+    println!("   cargo run practice {} --check", problem.bin_name);
+    Ok(())
+}
+
+/// Like [`scaffold`], but only the examples stay visible in `fn main`, or
+/// the first case when there are none, as in an interview. Run the rest
+/// with the harness's `--cases` flag.
+pub fn scaffold_interview(problem: &Problem) -> Result<(), String> {
+    let original = fs::read_to_string(&problem.path)
+        .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+    write_workspace(problem, &hide_cases(&blank_solution(&original)))
+}
+
+fn write_workspace(problem: &Problem, solution: &str) -> Result<(), String> {
+    let mut content = format!(
+        "{}{}\n\n{}\n\n",
+        HEADER_PREFIX,
+        problem.bin_name,
+        source::BLANK_PRELUDE
+    );
+    content.push_str(solution);
+
+    if let Some(parent) = std::path::Path::new(PRACTICE_PATH).parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("❌ Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(PRACTICE_PATH, content)
+        .map_err(|e| format!("❌ Failed to write {}: {}", PRACTICE_PATH, e))?;
+
+    history::record(&problem.bin_name, EventKind::Start, true)
+}
+
+/// Removes the `.case(..)` calls, keeping the first one when there are no
+/// `.example(..)` calls to show instead
+fn hide_cases(src: &str) -> String {
+    if src.contains(".example(") {
+        return source::remove_calls(src, "case", "");
+    }
+    match src.find(".case(") {
+        Some(first) => {
+            let keep = first + ".case(".len();
+            format!(
+                "{}{}",
+                &src[..keep],
+                source::remove_calls(&src[keep..], "case", "")
+            )
+        }
+        None => src.to_string(),
+    }
+}
+
+/// Runs the original tests against the practice attempt, records the
+/// outcome and optionally diffs it against the stored solution
+pub fn check(problem: &Problem, show_diff: bool) -> Result<bool, String> {
+    match current_problem() {
+        Some(name) if name == problem.bin_name => {}
+        Some(name) => {
+            return Err(format!(
+                "⚠️  {} holds an attempt at '{}', not '{}'.",
+                PRACTICE_PATH, name, problem.bin_name
+            ));
+        }
+        None => {
+            return Err(format!(
+                "⚠️  No practice attempt found. Run: cargo run practice {}",
+                problem.bin_name
+            ));
+        }
+    }
+
+    println!("\n🧪 Checking practice attempt for {}\n", problem.name);
+    println!("{}", "=".repeat(50));
+
+    let passed = runner::run_bin(PRACTICE_BIN);
+    history::record(&problem.bin_name, EventKind::Practice, passed)?;
+
+    if passed {
+        println!("\n🎉 Attempt recorded for {}!", problem.name);
+        if show_diff {
+            print_diff(problem)?;
+        }
+    } else {
+        println!("\n❌ Attempt failed, keep going!");
+    }
+
+    Ok(passed)
+}
+
+/// Reads the problem name out of the practice workspace header
+pub fn current_problem() -> Option<String> {
+    let content = fs::read_to_string(PRACTICE_PATH).ok()?;
+    let name = content.lines().next()?.strip_prefix(HEADER_PREFIX)?.trim();
+
+    if name.is_empty() || name == "none" {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// Strips alternative approaches and replaces solution method bodies
+/// with `todo!()`, leaving the rest of the test harness untouched
+fn blank_solution(original_src: &str) -> String {
+    let mut original = source::without_approaches(original_src);
+    for module in source::approach_modules(original_src) {
+        let path = format!("{}::", module.name);
+        original = source::remove_calls(&original, "approach", &path);
+        original = source::remove_calls(&original, "oracle", &path);
+    }
+    let original = original.as_str();
+
+    let split = source::main_body(original)
+        .and_then(|(open, _)| original[..open].rfind("fn main"))
+        .unwrap_or(original.len());
+
+    let head = source::strip_line_comments(&original[..split]);
+    let mut blanked = source::blank_bodies(&head);
+    blanked.push_str(&original[split..]);
+    blanked
+}
+
+/// Prints a line diff between the stored solution and the attempt
+fn print_diff(problem: &Problem) -> Result<(), String> {
+    let original = fs::read_to_string(&problem.path)
+        .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+    let attempt = fs::read_to_string(PRACTICE_PATH)
+        .map_err(|e| format!("❌ Failed to read {}: {}", PRACTICE_PATH, e))?;
+
+    let stored = solution_text(&source::strip_line_comments(&source::without_approaches(
+        &original,
+    )));
+    let attempted = solution_text(&source::strip_line_comments(&attempt));
+
+    println!("\n🔍 Stored solution vs your attempt:\n");
+    for line in diff_lines(&stored, &attempted) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Concatenates every `impl Solution` block in `src`
+fn solution_text(src: &str) -> Vec<String> {
+    source::solution_impls(src)
+        .into_iter()
+        .flat_map(|(start, end)| src[start..end].lines().map(str::to_string))
+        .collect()
+}
+
+/// Minimal LCS line diff: `-` lines are only in `old`, `+` only in `new`
+fn diff_lines(old: &[String], new: &[String]) -> Vec<String> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i].trim() == new[j].trim() {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i].trim() == new[j].trim() {
+            out.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    out.extend(old[i..].iter().map(|line| format!("- {}", line)));
+    out.extend(new[j..].iter().map(|line| format!("+ {}", line)));
+    out
+}
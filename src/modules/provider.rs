@@ -0,0 +1,424 @@
+use crate::modules::judge::{self, Judgement};
+use crate::modules::{cases, metadata};
+use std::fs;
+use std::path::PathBuf;
+
+/// Directory the `catalog` source reads from
+pub const DEFAULT_CATALOG: &str = "catalog";
+
+/// Extension of a catalog entry
+const CATALOG_EXTENSION: &str = "txt";
+
+/// Names accepted by [`source`]
+pub const SOURCES: &[&str] = &["catalog", "mock"];
+
+/// Names accepted by [`judge`]
+pub const JUDGES: &[&str] = &["local"];
+
+/// A problem as a provider describes it, before it becomes a solution file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProblemSpec {
+    /// Provider's identifier, e.g. "two-sum"
+    pub slug: String,
+    pub title: String,
+    pub difficulty: Option<String>,
+    pub link: Option<String>,
+    /// One-line problem statement
+    pub description: Option<String>,
+    /// The `impl Solution` stub
+    pub starter: String,
+    /// `(input, output)` pairs in LeetCode notation
+    pub examples: Vec<(String, String)>,
+}
+
+impl ProblemSpec {
+    /// Bin and file name of the solution: "two-sum" -> "two_sum"
+    pub fn bin_name(&self) -> String {
+        self.slug.replace('-', "_")
+    }
+}
+
+/// Where problem descriptions and starter code come from
+pub trait ProblemSource {
+    /// Shown to the user, e.g. "catalog"
+    fn name(&self) -> &str;
+
+    /// Slugs this source can fetch, sorted
+    fn list(&self) -> Result<Vec<String>, String>;
+
+    fn fetch(&self, slug: &str) -> Result<ProblemSpec, String>;
+}
+
+/// Where submissions are graded
+pub trait Judge {
+    /// Shown to the user, e.g. "local"
+    fn name(&self) -> &str;
+
+    /// Grades `source` as a solution to the problem with bin name `problem`
+    fn submit(&self, problem: &str, source: &str) -> Result<Judgement, String>;
+}
+
+/// The source registered under `name`, one of [`SOURCES`]
+pub fn source(name: &str) -> Result<Box<dyn ProblemSource>, String> {
+    match name {
+        "catalog" => Ok(Box::new(Catalog::new(DEFAULT_CATALOG))),
+        "mock" => Ok(Box::new(MockSource::default())),
+        _ => Err(unknown("source", name, SOURCES)),
+    }
+}
+
+/// The judge registered under `name`, one of [`JUDGES`]. `address` is
+/// where the local judge listens.
+pub fn judge(name: &str, address: &str) -> Result<Box<dyn Judge>, String> {
+    match name {
+        "local" => Ok(Box::new(LocalJudge::new(address))),
+        _ => Err(unknown("judge", name, JUDGES)),
+    }
+}
+
+fn unknown(kind: &str, name: &str, known: &[&str]) -> String {
+    format!(
+        "❌ Unknown {} '{}'\n💡 Available: {}",
+        kind,
+        name,
+        known.join(", ")
+    )
+}
+
+/// Problems stored as `<slug>.txt` files in a directory: the `//!` header
+/// of a solution file, the starter code, then the examples, e.g.
+///
+/// ```text
+/// //! title: Two Sum
+/// //! difficulty: Easy
+///
+/// impl Solution {
+///     pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
+///     }
+/// }
+///
+/// Input: nums = [2,7,11,15], target = 9
+/// Output: [0,1]
+/// ```
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    dir: PathBuf,
+}
+
+impl Catalog {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Catalog { dir: dir.into() }
+    }
+}
+
+impl ProblemSource for Catalog {
+    fn name(&self) -> &str {
+        "catalog"
+    }
+
+    fn list(&self) -> Result<Vec<String>, String> {
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| format!("❌ Failed to read {}: {}", self.dir.display(), e))?;
+        let mut slugs: Vec<String> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some(CATALOG_EXTENSION))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        slugs.sort();
+        Ok(slugs)
+    }
+
+    fn fetch(&self, slug: &str) -> Result<ProblemSpec, String> {
+        let path = self.dir.join(format!("{}.{}", slug, CATALOG_EXTENSION));
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("❌ Failed to read {}: {}", path.display(), e))?;
+        parse_entry(slug, &text).map_err(|e| format!("❌ {}: {}", path.display(), e))
+    }
+}
+
+/// Reads a catalog entry: header, starter code up to the first `Input:`,
+/// then the examples
+pub fn parse_entry(slug: &str, text: &str) -> Result<ProblemSpec, String> {
+    let meta = metadata::parse(text);
+    let body: Vec<&str> = text
+        .lines()
+        .skip_while(|line| line.trim().starts_with("//!"))
+        .collect();
+    let split = body
+        .iter()
+        .position(|line| line.trim().starts_with("Input:"))
+        .unwrap_or(body.len());
+
+    let starter = body[..split].join("\n").trim().to_string();
+    if !starter.contains("impl Solution") {
+        return Err("no `impl Solution` starter code".to_string());
+    }
+    let examples = cases::parse_file(&body[split..].join("\n"))?
+        .into_iter()
+        .map(|case| (case.input, case.output))
+        .collect();
+
+    Ok(ProblemSpec {
+        slug: slug.to_string(),
+        title: meta.title.unwrap_or_else(|| title_from_slug(slug)),
+        difficulty: meta.difficulty,
+        link: meta.link,
+        description: meta.description,
+        starter,
+        examples,
+    })
+}
+
+/// "two-sum" or "two_sum" -> "Two Sum"
+pub fn title_from_slug(slug: &str) -> String {
+    slug.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Problems kept in memory, for trying the workflow offline and in tests.
+/// The default holds a small sample.
+#[derive(Debug, Clone)]
+pub struct MockSource {
+    problems: Vec<ProblemSpec>,
+}
+
+impl MockSource {
+    /// A source with no problems
+    pub fn empty() -> Self {
+        MockSource {
+            problems: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, problem: ProblemSpec) -> Self {
+        self.problems.push(problem);
+        self
+    }
+}
+
+impl Default for MockSource {
+    fn default() -> Self {
+        MockSource::empty().with(ProblemSpec {
+            slug: "single-number".to_string(),
+            title: "Single Number".to_string(),
+            difficulty: Some("Easy".to_string()),
+            link: Some("https://leetcode.com/problems/single-number/".to_string()),
+            description: Some(
+                "Every element of `nums` appears twice except one; find it.".to_string(),
+            ),
+            starter: "impl Solution {\n    pub fn single_number(nums: Vec<i32>) -> i32 {\n        \n    }\n}"
+                .to_string(),
+            examples: vec![
+                ("nums = [2,2,1]".to_string(), "1".to_string()),
+                ("nums = [4,1,2,1,2]".to_string(), "4".to_string()),
+                ("nums = [1]".to_string(), "1".to_string()),
+            ],
+        })
+    }
+}
+
+impl ProblemSource for MockSource {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    fn list(&self) -> Result<Vec<String>, String> {
+        let mut slugs: Vec<String> = self.problems.iter().map(|p| p.slug.clone()).collect();
+        slugs.sort();
+        Ok(slugs)
+    }
+
+    fn fetch(&self, slug: &str) -> Result<ProblemSpec, String> {
+        self.problems
+            .iter()
+            .find(|p| p.slug == slug)
+            .cloned()
+            .ok_or_else(|| format!("❌ The mock source has no problem '{}'", slug))
+    }
+}
+
+/// The judge started by `dsa judge serve`
+#[derive(Debug, Clone)]
+pub struct LocalJudge {
+    address: String,
+}
+
+impl LocalJudge {
+    pub fn new(address: &str) -> Self {
+        LocalJudge {
+            address: address.to_string(),
+        }
+    }
+}
+
+impl Judge for LocalJudge {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn submit(&self, problem: &str, source: &str) -> Result<Judgement, String> {
+        judge::submit(&self.address, problem, source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::judge::{FailingCase, Verdict};
+    use std::time::Duration;
+
+    const ENTRY: &str = "//! title: Two Sum\n//! difficulty: Easy\n\nimpl Solution {\n    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n    }\n}\n\nInput: nums = [2,7,11,15], target = 9\nOutput: [0,1]\nExplanation: nums[0] + nums[1] == 9\n\nInput: nums = [3,3], target = 6\nOutput: [0,1]\n";
+
+    fn temp_catalog(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dsa-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn parses_catalog_entry() {
+        let spec = parse_entry("two-sum", ENTRY).unwrap();
+
+        assert_eq!(spec.title, "Two Sum");
+        assert_eq!(spec.difficulty.as_deref(), Some("Easy"));
+        assert_eq!(spec.bin_name(), "two_sum");
+        assert!(spec.starter.starts_with("impl Solution {"));
+        assert!(spec.starter.ends_with('}'));
+        assert_eq!(
+            spec.examples,
+            vec![
+                (
+                    "nums = [2,7,11,15], target = 9".to_string(),
+                    "[0,1]".to_string()
+                ),
+                ("nums = [3,3], target = 6".to_string(), "[0,1]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn entry_without_starter_is_rejected() {
+        assert!(parse_entry("two-sum", "Input: x = 1\nOutput: 1\n").is_err());
+    }
+
+    #[test]
+    fn title_defaults_to_slug() {
+        let spec = parse_entry("valid-anagram", "impl Solution {}\n").unwrap();
+        assert_eq!(spec.title, "Valid Anagram");
+        assert!(spec.examples.is_empty());
+    }
+
+    #[test]
+    fn catalog_lists_and_fetches_files() {
+        let dir = temp_catalog("catalog");
+        fs::write(dir.join("two-sum.txt"), ENTRY).unwrap();
+        fs::write(dir.join("notes.md"), "not an entry").unwrap();
+
+        let catalog = Catalog::new(&dir);
+        assert_eq!(catalog.list().unwrap(), vec!["two-sum".to_string()]);
+        assert_eq!(catalog.fetch("two-sum").unwrap().examples.len(), 2);
+        assert!(catalog.fetch("three-sum").is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn mock_source_serves_its_problems() {
+        let source = MockSource::empty().with(ProblemSpec {
+            slug: "two-sum".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(source.list().unwrap(), vec!["two-sum".to_string()]);
+        assert_eq!(source.fetch("two-sum").unwrap().slug, "two-sum");
+        assert!(source.fetch("single-number").is_err());
+        assert!(MockSource::default().fetch("single-number").is_ok());
+    }
+
+    /// Serves `handle` on an ephemeral port until the returned server is
+    /// unblocked
+    fn judge_server(
+        handle: fn(&str) -> Result<Judgement, String>,
+    ) -> (std::sync::Arc<tiny_http::Server>, String) {
+        let server = std::sync::Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+        let address = server.server_addr().to_ip().unwrap().to_string();
+        let serving = std::sync::Arc::clone(&server);
+        std::thread::spawn(move || judge::respond(&serving, handle));
+        (server, address)
+    }
+
+    #[test]
+    fn local_judge_returns_the_servers_judgement() {
+        let (server, address) = judge_server(|body| {
+            let request: serde_json::Value = serde_json::from_str(body).unwrap();
+            assert_eq!(request["problem"], "two_sum");
+            assert_eq!(request["source"], "impl Solution {}");
+            Ok(Judgement {
+                verdict: Verdict::WrongAnswer,
+                passed: 1,
+                total: 2,
+                failing: Some(FailingCase {
+                    index: 2,
+                    input: "nums = [3,3], target = 6".to_string(),
+                    expected: Some("[0,1]".to_string()),
+                    got: Some("[1,0]".to_string()),
+                }),
+                max_time: Duration::from_millis(250),
+                message: String::new(),
+            })
+        });
+
+        let judgement = LocalJudge::new(&address)
+            .submit("two_sum", "impl Solution {}")
+            .unwrap();
+        assert_eq!(judgement.verdict, Verdict::WrongAnswer);
+        assert_eq!((judgement.passed, judgement.total), (1, 2));
+        assert_eq!(judgement.max_time, Duration::from_millis(250));
+        let failing = judgement.failing.unwrap();
+        assert_eq!(failing.index, 2);
+        assert_eq!(failing.got.as_deref(), Some("[1,0]"));
+
+        server.unblock();
+    }
+
+    #[test]
+    fn local_judge_reports_server_errors() {
+        let (server, address) = judge_server(|_| Err("❌ Problem 'nope' not found".to_string()));
+
+        let err = LocalJudge::new(&address).submit("nope", "").unwrap_err();
+        assert_eq!(err, "❌ Problem 'nope' not found");
+
+        server.unblock();
+    }
+
+    #[test]
+    fn local_judge_without_a_server_is_an_error() {
+        // Bind and drop a listener so nothing is on the port
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .to_string();
+
+        let err = LocalJudge::new(&address).submit("two_sum", "").unwrap_err();
+        assert!(err.contains("No judge at"), "{}", err);
+    }
+
+    #[test]
+    fn unknown_names_are_errors() {
+        assert!(source("leetcode").is_err());
+        assert!(judge("leetcode", judge::DEFAULT_ADDRESS).is_err());
+        assert!(judge("mock", "").is_err());
+        assert_eq!(judge("local", "").unwrap().name(), "local");
+    }
+}
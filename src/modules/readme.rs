@@ -0,0 +1,90 @@
+use crate::modules::discovery::Problem;
+use crate::modules::metadata;
+use std::fs;
+
+const README_PATH: &str = "README.md";
+const SECTION_START: &str = "<!-- problems:start -->";
+const SECTION_END: &str = "<!-- problems:end -->";
+
+/// Regenerates the problem table between the README markers, appending
+/// the section when the markers are missing
+pub fn update_readme(problems: &[Problem]) -> Result<(), String> {
+    let content = fs::read_to_string(README_PATH)
+        .map_err(|e| format!("❌ Failed to read {}: {}", README_PATH, e))?;
+
+    let section = format!(
+        "{}\n{}{}",
+        SECTION_START,
+        render_table(problems),
+        SECTION_END
+    );
+
+    let new_content = match (content.find(SECTION_START), content.find(SECTION_END)) {
+        (Some(start), Some(end)) if start < end => {
+            format!(
+                "{}{}{}",
+                &content[..start],
+                section,
+                &content[end + SECTION_END.len()..]
+            )
+        }
+        (None, None) => format!("{}\n\n## Problems\n\n{}\n", content.trim_end(), section),
+        _ => {
+            return Err(format!(
+                "❌ {} has unbalanced {} / {} markers",
+                README_PATH, SECTION_START, SECTION_END
+            ));
+        }
+    };
+
+    fs::write(README_PATH, new_content)
+        .map_err(|e| format!("❌ Failed to write {}: {}", README_PATH, e))?;
+
+    println!(
+        "✅ Updated {} with {} problems",
+        README_PATH,
+        problems.len()
+    );
+    Ok(())
+}
+
+/// Markdown tables of all problems, one per category
+fn render_table(problems: &[Problem]) -> String {
+    let mut categories: Vec<&str> = problems.iter().map(|p| p.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+
+    let mut out = String::new();
+    for category in categories {
+        let in_category: Vec<&Problem> =
+            problems.iter().filter(|p| p.category == category).collect();
+
+        out.push_str(&format!(
+            "\n### {} ({})\n\n",
+            in_category[0].category_label(),
+            in_category.len()
+        ));
+        out.push_str("| Problem | Difficulty | Time | Space | Solution |\n");
+        out.push_str("|---------|------------|------|-------|----------|\n");
+
+        for problem in in_category {
+            let meta = metadata::load(problem);
+            let title = match &meta.link {
+                Some(link) => format!("[{}]({})", meta.title_or(problem), link),
+                None => meta.title_or(problem).to_string(),
+            };
+
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | [`{}.rs`]({}) |\n",
+                title,
+                meta.difficulty_label(),
+                meta.time.as_deref().unwrap_or("-"),
+                meta.space.as_deref().unwrap_or("-"),
+                problem.bin_name,
+                problem.path
+            ));
+        }
+    }
+    out.push('\n');
+    out
+}
@@ -1,27 +1,207 @@
+use crate::modules::diagnostics::{self, Diagnostic};
 use crate::modules::discovery::Problem;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Runs a specific problem using cargo run --bin
-pub fn run_problem(problem: &Problem) {
+pub fn run_problem(problem: &Problem) -> bool {
     println!("\n🚀 Running: {} from {}\n", problem.name, problem.category);
     println!("{}", "=".repeat(50));
 
-    let status = Command::new("cargo")
-        .arg("run")
-        .arg("--bin")
-        .arg(&problem.bin_name)
-        .status();
+    run_bin(&problem.bin_name)
+}
+
+/// Runs any bin target with cargo, returning whether it succeeded
+pub fn run_bin(bin_name: &str) -> bool {
+    run_bin_with(bin_name, false, &[])
+}
 
-    match status {
+/// Runs a bin target, optionally in release mode, passing `args` through.
+/// Compile errors are summarized instead of dumped raw.
+pub fn run_bin_with(bin_name: &str, release: bool, args: &[String]) -> bool {
+    let build = match build_bins(&[bin_name.to_string()], release) {
+        Ok(build) => build,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let Some(artifact) = build.artifacts.first() else {
+        println!("\n🔨 {} failed to compile:\n", bin_name);
+        print!("{}", diagnostics::render(&build.diagnostics));
+        println!("\n❌ Build failed!");
+        return false;
+    };
+
+    match Command::new(&artifact.executable).args(args).status() {
         Ok(exit_status) => {
             if exit_status.success() {
                 println!("\n✅ Execution completed successfully!");
+                true
             } else {
                 println!("\n❌ Execution failed!");
+                false
             }
         }
         Err(e) => {
-            eprintln!("❌ Failed to run cargo: {}", e);
+            eprintln!("❌ Failed to run {}: {}", artifact.executable.display(), e);
+            false
+        }
+    }
+}
+
+/// A bin target compiled by [`build_bins`]
+#[derive(Debug, Clone)]
+pub struct Artifact {
+    pub bin_name: String,
+    pub executable: PathBuf,
+}
+
+/// Outcome of running one artifact in a batch
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    pub bin_name: String,
+    pub passed: bool,
+    pub elapsed: Duration,
+    /// Combined stdout and stderr
+    pub output: String,
+}
+
+/// Executables and compiler messages from one cargo build
+#[derive(Debug, Clone, Default)]
+pub struct Build {
+    pub artifacts: Vec<Artifact>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Build {
+    /// Messages reported while compiling `target`
+    pub fn diagnostics_for(&self, target: &str) -> Vec<Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.target == target)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Compiles `bins` with a single cargo invocation and collects the
+/// executables and diagnostics from its JSON output. `--keep-going` lets
+/// the other bins build when one of them fails to compile.
+pub fn build_bins(bins: &[String], release: bool) -> Result<Build, String> {
+    build_package_bins(None, bins, release)
+}
+
+/// Like [`build_bins`], for the package at `manifest_path` when given
+pub fn build_package_bins(
+    manifest_path: Option<&Path>,
+    bins: &[String],
+    release: bool,
+) -> Result<Build, String> {
+    let mut command = Command::new("cargo");
+    command
+        .args(["build", "--keep-going", "--message-format=json"])
+        .stderr(Stdio::inherit());
+    if let Some(path) = manifest_path {
+        command.arg("--manifest-path").arg(path);
+    }
+    if release {
+        command.arg("--release");
+    }
+    for bin in bins {
+        command.arg("--bin").arg(bin);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("❌ Failed to run cargo: {}", e))?;
+
+    let mut build = Build::default();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if let Some(diagnostic) = diagnostics::parse_message(&message) {
+            build.diagnostics.push(diagnostic);
+        } else if message["reason"] == "compiler-artifact"
+            && let Some(bin_name) = message["target"]["name"].as_str()
+            && let Some(executable) = message["executable"].as_str()
+            && bins.iter().any(|bin| bin == bin_name)
+        {
+            build.artifacts.push(Artifact {
+                bin_name: bin_name.to_string(),
+                executable: PathBuf::from(executable),
+            });
         }
     }
+    Ok(build)
+}
+
+/// Runs every artifact directly, spreading them across the available
+/// cores. Results keep the order of `artifacts`.
+pub fn run_artifacts(artifacts: &[Artifact], args: &[String]) -> Vec<BatchResult> {
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(artifacts.len().max(1));
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; artifacts.len()]);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(artifact) = artifacts.get(i) else {
+                        break;
+                    };
+                    let result = run_artifact(artifact, args);
+                    if let Ok(mut results) = results.lock() {
+                        results[i] = Some(result);
+                    }
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn run_artifact(artifact: &Artifact, args: &[String]) -> BatchResult {
+    let start = Instant::now();
+    let output = Command::new(&artifact.executable).args(args).output();
+    let elapsed = start.elapsed();
+
+    match output {
+        Ok(output) => BatchResult {
+            bin_name: artifact.bin_name.clone(),
+            passed: output.status.success(),
+            elapsed,
+            output: format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        },
+        Err(e) => BatchResult {
+            bin_name: artifact.bin_name.clone(),
+            passed: false,
+            elapsed,
+            output: format!(
+                "❌ Failed to start {}: {}",
+                artifact.executable.display(),
+                e
+            ),
+        },
+    }
 }
@@ -0,0 +1,878 @@
+use std::fmt;
+
+/// Returns the index of the `}` that closes the `{` at `open`.
+///
+/// Skips braces inside line comments, block comments and string literals.
+pub fn matching_brace(src: &str, open: usize) -> Option<usize> {
+    let bytes = src.as_bytes();
+    let mut depth = 0usize;
+    let mut i = open;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'\'' if bytes.get(i + 2) == Some(&b'\'') => {
+                // Char literals like '{' would otherwise unbalance the count
+                i += 2;
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Byte ranges `(start, end)` of every `impl Solution { ... }` block,
+/// where `start` is the beginning of the `impl` line and `end` is one
+/// past the closing brace.
+pub fn solution_impls(src: &str) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+
+    for line in src.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        if !line.trim_start().starts_with("impl Solution") {
+            continue;
+        }
+        if let Some(open) = src[start..].find('{').map(|i| start + i)
+            && let Some(close) = matching_brace(src, open)
+        {
+            blocks.push((start, close + 1));
+        }
+    }
+
+    blocks
+}
+
+/// Byte range of the body of `fn main`, including its braces
+pub fn main_body(src: &str) -> Option<(usize, usize)> {
+    let start = src.find("fn main()")?;
+    let open = start + src[start..].find('{')?;
+    let close = matching_brace(src, open)?;
+    Some((open, close + 1))
+}
+
+/// Finds the bodies of the functions declared directly inside the block
+/// whose braces span `block`, returning their `{ ... }` byte ranges.
+pub fn fn_bodies(src: &str, block: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut bodies = Vec::new();
+    let Some(open) = src[block.0..block.1].find('{').map(|i| block.0 + i) else {
+        return bodies;
+    };
+
+    let mut i = open + 1;
+    while i < block.1 {
+        let rest = &src[i..block.1];
+        let Some(pos) = find_fn_keyword(rest) else {
+            break;
+        };
+        let fn_start = i + pos;
+
+        // The body opens at the first `{` after the signature
+        let Some(body_open) = src[fn_start..block.1].find('{').map(|j| fn_start + j) else {
+            break;
+        };
+        let Some(body_close) = matching_brace(src, body_open) else {
+            break;
+        };
+
+        bodies.push((body_open, body_close + 1));
+        i = body_close + 1;
+    }
+
+    bodies
+}
+
+/// Crate attribute for code with `todo!()` bodies, whose parameters and
+/// imports stay unused until it is solved
+pub const BLANK_PRELUDE: &str = "#![allow(unused_imports, unused_mut, unused_variables)]";
+
+/// Replaces the body of every method in the `impl Solution` blocks of
+/// `src` with `todo!()`
+pub fn blank_bodies(src: &str) -> String {
+    let mut out = src.to_string();
+    // Back to front so earlier offsets stay valid
+    for block in solution_impls(src).into_iter().rev() {
+        for (open, close) in fn_bodies(src, block).into_iter().rev() {
+            out.replace_range(open..close, "{\n        todo!()\n    }");
+        }
+    }
+    out
+}
+
+/// Position of the next `fn` keyword that starts a non-comment line
+fn find_fn_keyword(text: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if !trimmed.starts_with("//") {
+            for prefix in ["pub fn ", "fn ", "pub(crate) fn "] {
+                if trimmed.starts_with(prefix) {
+                    return Some(offset + indent);
+                }
+            }
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Removes full-line `//` comments (keeping `///` and `//!` doc comments)
+/// and collapses the blank lines they leave behind.
+pub fn strip_line_comments(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut previous_blank = false;
+
+    for line in src.lines() {
+        let trimmed = line.trim_start();
+        if is_line_comment(trimmed) {
+            continue;
+        }
+
+        // Drop runs of blank lines and blank lines that open a block
+        let blank = trimmed.is_empty();
+        if blank && (previous_blank || out.trim_end_matches('\n').ends_with('{')) {
+            continue;
+        }
+        previous_blank = blank;
+
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    out.trim_start_matches('\n').to_string()
+}
+
+/// Whether `line`, trimmed, is a plain `//` comment; `///` and `//!` doc
+/// comments are kept as code
+fn is_line_comment(line: &str) -> bool {
+    line.starts_with("//") && !line.starts_with("///") && !line.starts_with("//!")
+}
+
+/// Counts non-blank, non-comment lines across every `impl Solution` block
+pub fn solution_loc(src: &str) -> usize {
+    solution_impls(src)
+        .into_iter()
+        .flat_map(|(start, end)| src[start..end].lines())
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count()
+}
+
+/// Splits `text` on commas that are not nested in brackets or strings
+pub fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut current = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_string = !in_string,
+            '\\' if in_string => {
+                current.push(c);
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+                continue;
+            }
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            ',' if depth == 0 && !in_string => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Text between the delimiter at `open` and its matching closer
+fn delimited(src: &str, open: usize) -> Option<&str> {
+    let (opener, closer) = match src.as_bytes().get(open)? {
+        b'(' => (b'(', b')'),
+        b'[' => (b'[', b']'),
+        _ => return None,
+    };
+
+    let mut depth = 0;
+    for (i, &byte) in src.as_bytes().iter().enumerate().skip(open) {
+        if byte == opener {
+            depth += 1;
+        } else if byte == closer {
+            depth -= 1;
+            if depth == 0 {
+                return Some(&src[open + 1..i]);
+            }
+        }
+    }
+    None
+}
+
+/// Best-effort extraction of the test cases written in `fn main`.
+///
+/// Understands the three harness shapes used across the solutions:
+/// `assert_eq!(Solution::f(..), expected)`, direct calls with a trailing
+/// `// expected` comment, and a `vec![(input, expected), ..]` table.
+pub fn harness_cases(src: &str) -> Vec<String> {
+    let Some((open, close)) = main_body(src) else {
+        return Vec::new();
+    };
+    let body = &src[open..close];
+
+    let mut cases = Vec::new();
+    for line in body.lines() {
+        let trimmed = line.trim();
+        let Some(call) = trimmed.find("Solution::") else {
+            continue;
+        };
+        let Some(paren) = trimmed[call..].find('(').map(|i| call + i) else {
+            continue;
+        };
+        let Some(args) = delimited(trimmed, paren) else {
+            continue;
+        };
+
+        // Calls on loop variables are covered by the table below
+        if !args.contains('[') && !args.chars().any(|c| c.is_ascii_digit()) {
+            continue;
+        }
+
+        let name = &trimmed[call + "Solution::".len()..paren];
+        let expected = if let Some(assert) = trimmed.strip_prefix("assert_eq!(") {
+            // Only the macro's own paren; the expected value may end in one
+            assert
+                .trim_end()
+                .trim_end_matches(';')
+                .strip_suffix(')')
+                .and_then(|args| split_top_level(args).get(1).cloned())
+        } else {
+            trimmed
+                .split_once("//")
+                .map(|(_, comment)| comment.trim().to_string())
+        };
+
+        cases.push(match expected {
+            Some(expected) => format!("{}({}) → {}", name, args, expected),
+            None => format!("{}({})", name, args),
+        });
+    }
+
+    if cases.is_empty() {
+        cases.extend(
+            harness_case_calls(src)
+                .into_iter()
+                .map(|case| format!("{} → {}", case.input, case.expected)),
+        );
+    }
+
+    if cases.is_empty()
+        && let Some(table) = body.find("= vec![").map(|i| i + "= vec!".len())
+        && let Some(rows) = delimited(body, table)
+    {
+        let rows = strip_line_comments(rows);
+        for row in split_top_level(&rows) {
+            let row = row.split_whitespace().collect::<Vec<_>>().join(" ");
+            let row = row
+                .strip_prefix('(')
+                .and_then(|r| r.strip_suffix(')'))
+                .unwrap_or(&row);
+            cases.push(row.trim().trim_end_matches(',').to_string());
+        }
+    }
+
+    cases
+}
+
+/// A `.case(..)` or `.example(..)` call in a harness chain
+#[derive(Debug, Clone)]
+pub struct HarnessCase {
+    /// Rust expression for `.case`, LeetCode notation for `.example`
+    pub input: String,
+    pub expected: String,
+    /// 1-based line of the call in the file
+    pub line: usize,
+}
+
+/// A `.cases(..)` or `.cases_file(..)` call in a harness chain, which
+/// adds a number of cases only known when the harness runs
+#[derive(Debug, Clone)]
+pub struct DynamicCases {
+    /// `.cases` or `.cases_file`
+    pub method: &'static str,
+    /// 1-based line of the call in the file
+    pub line: usize,
+    /// How many `.case(..)` and `.example(..)` calls run before it
+    pub after: usize,
+}
+
+/// How a call in a harness chain adds cases
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseCall {
+    Case,
+    Example,
+    Dynamic(&'static str),
+}
+
+/// Cases added with `.case(..)` and `.example(..)` in `fn main`, in the
+/// order the harness runs them. Cases added by [`dynamic_case_call`]s are
+/// not included.
+pub fn harness_case_calls(src: &str) -> Vec<HarnessCase> {
+    let Some((open, close)) = main_body(src) else {
+        return Vec::new();
+    };
+    let body = strip_line_comments(&src[open..close]);
+    let lines = case_call_lines(src);
+
+    case_call_offsets(&body)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(n, (paren, call))| {
+            let is_example = match call {
+                CaseCall::Case => false,
+                CaseCall::Example => true,
+                CaseCall::Dynamic(_) => return None,
+            };
+            let args = split_top_level(delimited(&body, paren)?);
+            let [input, expected] = args.as_slice() else {
+                return None;
+            };
+            let text = |arg: &str| {
+                let arg = arg.split_whitespace().collect::<Vec<_>>().join(" ");
+                if is_example { unquote(&arg) } else { arg }
+            };
+            Some(HarnessCase {
+                input: text(input),
+                expected: text(expected),
+                line: lines.get(n).copied().unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// The first `.cases(..)` or `.cases_file(..)` call in `fn main`. Cases
+/// after it can't be matched to a line or an index without running the
+/// harness.
+pub fn dynamic_case_call(src: &str) -> Option<DynamicCases> {
+    let (open, close) = main_body(src)?;
+    let body = strip_line_comments(&src[open..close]);
+    let calls = case_call_offsets(&body);
+    let n = calls
+        .iter()
+        .position(|(_, call)| matches!(call, CaseCall::Dynamic(_)))?;
+    let CaseCall::Dynamic(method) = calls[n].1 else {
+        return None;
+    };
+    Some(DynamicCases {
+        method,
+        line: case_call_lines(src).get(n).copied().unwrap_or(0),
+        after: n,
+    })
+}
+
+/// 1-based line of every call [`case_call_offsets`] finds in `fn main`
+fn case_call_lines(src: &str) -> Vec<usize> {
+    let Some((open, close)) = main_body(src) else {
+        return Vec::new();
+    };
+
+    // Comment lines are dropped whole, so the n-th call in the stripped
+    // body is the n-th call outside a comment in the original source
+    case_call_offsets(&src[open..close])
+        .into_iter()
+        .map(|(offset, _)| open + offset)
+        .filter(|&offset| {
+            let line_start = src[..offset].rfind('\n').map_or(0, |i| i + 1);
+            !is_line_comment(src[line_start..offset].trim_start())
+        })
+        .map(|offset| src[..offset].lines().count().max(1))
+        .collect()
+}
+
+/// Offsets of the opening parenthesis of every call that adds cases in
+/// `body`, in order
+fn case_call_offsets(body: &str) -> Vec<(usize, CaseCall)> {
+    let mut calls: Vec<(usize, CaseCall)> = Vec::new();
+    for (method, call) in [
+        (".case(", CaseCall::Case),
+        (".example(", CaseCall::Example),
+        (".cases(", CaseCall::Dynamic(".cases")),
+        (".cases_file(", CaseCall::Dynamic(".cases_file")),
+    ] {
+        calls.extend(
+            body.match_indices(method)
+                .map(|(i, m)| (i + m.len() - 1, call)),
+        );
+    }
+    calls.sort_unstable_by_key(|&(offset, _)| offset);
+    calls
+}
+
+/// Contents of a `"..."` or `r#"..."#` string literal
+fn unquote(literal: &str) -> String {
+    let raw = literal
+        .strip_prefix("r#\"")
+        .and_then(|s| s.strip_suffix("\"#"))
+        .or_else(|| {
+            literal
+                .strip_prefix("r\"")
+                .and_then(|s| s.strip_suffix('"'))
+        });
+    match raw {
+        Some(raw) => raw.to_string(),
+        None => literal
+            .trim_matches('"')
+            .replace("\\\"", "\"")
+            .replace("\\\\", "\\"),
+    }
+}
+
+/// An earlier approach kept as commented-out code
+#[derive(Debug, Clone)]
+pub struct CommentedApproach {
+    /// Prose comment lines that precede the code
+    pub note: String,
+    /// The uncommented, dedented code
+    pub code: String,
+}
+
+/// Collects runs of `//` lines that contain a commented-out function
+pub fn commented_approaches(src: &str) -> Vec<CommentedApproach> {
+    let mut approaches = Vec::new();
+    let mut run: Vec<&str> = Vec::new();
+
+    // A trailing sentinel flushes the last run
+    for line in src.lines().chain(std::iter::once("<end>")) {
+        let trimmed = line.trim_start();
+        if is_line_comment(trimmed) {
+            run.push(trimmed.trim_start_matches("//"));
+            continue;
+        }
+        if trimmed.is_empty() && !run.is_empty() {
+            run.push("");
+            continue;
+        }
+
+        if let Some(approach) = parse_commented_run(&run) {
+            approaches.push(approach);
+        }
+        run.clear();
+    }
+
+    approaches
+}
+
+fn parse_commented_run(run: &[&str]) -> Option<CommentedApproach> {
+    let code_start = run.iter().position(|line| looks_like_code(line))?;
+    if !run.iter().any(|line| line.contains("fn ")) {
+        return None;
+    }
+
+    let note = run[..code_start]
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let code = dedent(&run[code_start..].join("\n"));
+
+    Some(CommentedApproach {
+        note,
+        code: code.trim_matches('\n').to_string(),
+    })
+}
+
+fn looks_like_code(line: &str) -> bool {
+    let line = line.trim();
+    line.contains("fn ")
+        || line.starts_with("struct ")
+        || line.starts_with("impl ")
+        || line.ends_with(';')
+        || line.ends_with('{')
+        || line.ends_with('}')
+}
+
+/// An alternative approach declared as `mod <name> { impl Solution { .. } }`
+#[derive(Debug, Clone)]
+pub struct ApproachModule {
+    pub name: String,
+    /// Byte range of the whole `mod` item, including a preceding comment
+    pub range: (usize, usize),
+    /// Comment lines directly above the `mod`
+    pub note: String,
+    /// The module body, dedented
+    pub code: String,
+}
+
+/// Finds every top-level module that contains an `impl Solution`
+pub fn approach_modules(src: &str) -> Vec<ApproachModule> {
+    let mut modules = Vec::new();
+    let mut offset = 0;
+    let mut comment_start: Option<usize> = None;
+    let mut comment_lines: Vec<&str> = Vec::new();
+
+    for line in src.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        if line.starts_with("//") && !line.starts_with("//!") {
+            comment_start.get_or_insert(start);
+            comment_lines.push(line.trim_start_matches('/').trim());
+            continue;
+        }
+
+        if let Some(name) = line
+            .strip_prefix("mod ")
+            .and_then(|rest| rest.trim().strip_suffix('{'))
+            .map(str::trim)
+            && let Some(open) = src[start..].find('{').map(|i| start + i)
+            && let Some(close) = matching_brace(src, open)
+        {
+            let body = &src[open + 1..close];
+            if !solution_impls(body).is_empty() {
+                let range_end = src[close..].find('\n').map_or(src.len(), |i| close + i + 1);
+
+                modules.push(ApproachModule {
+                    name: name.to_string(),
+                    range: (comment_start.unwrap_or(start), range_end),
+                    note: comment_lines.join(" "),
+                    code: dedent(body.trim_matches('\n')),
+                });
+            }
+        }
+
+        comment_start = None;
+        comment_lines.clear();
+    }
+
+    modules
+}
+
+/// Removes the smallest common indentation from every non-blank line
+pub fn dedent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    text.lines()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Removes every `.method(..)` call whose arguments mention `needle`,
+/// e.g. the harness registration of a dropped approach module
+pub fn remove_calls(src: &str, method: &str, needle: &str) -> String {
+    let pattern = format!(".{}(", method);
+    let mut out = src.to_string();
+    let mut search_from = 0;
+
+    while let Some(pos) = out[search_from..].find(&pattern).map(|i| search_from + i) {
+        let paren = pos + pattern.len() - 1;
+        let Some(args) = delimited(&out, paren) else {
+            break;
+        };
+
+        if args.contains(needle) {
+            let end = paren + args.len() + 2;
+            // Take the call's own line indentation with it
+            let start = out[..pos]
+                .trim_end_matches([' ', '\t'])
+                .strip_suffix('\n')
+                .map_or(pos, |s| s.len());
+            out.replace_range(start..end, "");
+            search_from = start;
+        } else {
+            search_from = paren + 1;
+        }
+    }
+
+    out
+}
+
+/// `src` with every approach module removed, keeping only the main solution
+pub fn without_approaches(src: &str) -> String {
+    let mut out = src.to_string();
+    for module in approach_modules(src).into_iter().rev() {
+        out.replace_range(module.range.0..module.range.1, "");
+    }
+    out
+}
+
+/// A method of `impl Solution`, as declared in the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    /// `(name, type)` pairs, e.g. `("nums", "&mut Vec<i32>")`
+    pub params: Vec<(String, String)>,
+    /// `None` when the method returns `()`
+    pub output: Option<String>,
+}
+
+impl Signature {
+    /// The first `&mut` parameter, through which a method returning `()`
+    /// hands back its result
+    pub fn mutated(&self) -> Option<&(String, String)> {
+        self.params.iter().find(|(_, ty)| ty.starts_with("&mut"))
+    }
+
+    /// Whether the method works by mutating one of its arguments
+    pub fn mutates(&self) -> bool {
+        self.mutated().is_some()
+    }
+}
+
+/// `rotate(nums: &mut Vec<i32>, k: i32)`, followed by `-> T` when the
+/// method returns a value
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{}: {}", name, ty))
+            .collect();
+        write!(f, "{}({})", self.name, params.join(", "))?;
+        if let Some(output) = &self.output {
+            write!(f, " -> {}", output)?;
+        }
+        Ok(())
+    }
+}
+
+/// Signatures of the `pub fn`s in the top-level `impl Solution` blocks,
+/// in order. Approach modules are skipped, and a file that doesn't parse
+/// has none.
+pub fn solution_signatures(src: &str) -> Vec<Signature> {
+    let Ok(file) = syn::parse_file(src) else {
+        return Vec::new();
+    };
+
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Impl(block) if block.trait_.is_none() && is_solution(&block.self_ty) => {
+                Some(block)
+            }
+            _ => None,
+        })
+        .flat_map(|block| &block.items)
+        .filter_map(|item| match item {
+            syn::ImplItem::Fn(method) if matches!(method.vis, syn::Visibility::Public(_)) => {
+                signature(&method.sig)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_solution(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.is_ident("Solution"))
+}
+
+/// `None` for methods taking `self`, which LeetCode's stubs never do
+fn signature(sig: &syn::Signature) -> Option<Signature> {
+    let mut params = Vec::new();
+    for input in &sig.inputs {
+        let syn::FnArg::Typed(param) = input else {
+            return None;
+        };
+        let name = match param.pat.as_ref() {
+            syn::Pat::Ident(ident) => ident.ident.to_string(),
+            _ => "_".to_string(),
+        };
+        params.push((name, type_name(&param.ty)));
+    }
+
+    let output = match &sig.output {
+        syn::ReturnType::Default => None,
+        syn::ReturnType::Type(_, ty) => Some(type_name(ty)).filter(|ty| ty != "()"),
+    };
+
+    Some(Signature {
+        name: sig.ident.to_string(),
+        params,
+        output,
+    })
+}
+
+/// A type as it would be written by hand: `Option<Box<ListNode>>`,
+/// `&mut Vec<i32>`. Kinds LeetCode signatures don't use print as `_`.
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| {
+                let syn::PathArguments::AngleBracketed(generics) = &segment.arguments else {
+                    return segment.ident.to_string();
+                };
+                let args: Vec<String> = generics
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => type_name(ty),
+                        syn::GenericArgument::Lifetime(lifetime) => lifetime.to_string(),
+                        _ => "_".to_string(),
+                    })
+                    .collect();
+                format!("{}<{}>", segment.ident, args.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("::"),
+        syn::Type::Reference(reference) => {
+            let lifetime = reference
+                .lifetime
+                .as_ref()
+                .map_or(String::new(), |lifetime| format!("{} ", lifetime));
+            let mutability = if reference.mutability.is_some() {
+                "mut "
+            } else {
+                ""
+            };
+            format!("&{}{}{}", lifetime, mutability, type_name(&reference.elem))
+        }
+        syn::Type::Tuple(tuple) => {
+            let elems: Vec<String> = tuple.elems.iter().map(type_name).collect();
+            match elems.as_slice() {
+                [single] => format!("({},)", single),
+                _ => format!("({})", elems.join(", ")),
+            }
+        }
+        syn::Type::Slice(slice) => format!("[{}]", type_name(&slice.elem)),
+        syn::Type::Paren(paren) => type_name(&paren.elem),
+        syn::Type::Group(group) => type_name(&group.elem),
+        _ => "_".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assert_cases_keep_parens_of_the_expected_value() {
+        let src = "fn main() {\n    assert_eq!(Solution::find(vec![1, 2]), Some(2));\n    assert_eq!(Solution::pair(3), (1, 2));\n}\n";
+        let cases = harness_cases(src);
+
+        assert_eq!(cases.len(), 2);
+        assert!(cases[0].ends_with("Some(2)"), "{}", cases[0]);
+        assert!(cases[1].ends_with("(1, 2)"), "{}", cases[1]);
+    }
+
+    #[test]
+    fn case_lines_count_doc_comments_as_code() {
+        let src = "fn main() {\n    Harness::new(\"F\")\n        // .case((0,), 0)\n        /// .case((1,), 1)\n        .case((2,), 2)\n        .run();\n}\n";
+        let calls = harness_case_calls(src);
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].input.as_str(), calls[0].line), ("(1,)", 4));
+        assert_eq!((calls[1].input.as_str(), calls[1].line), ("(2,)", 5));
+    }
+
+    #[test]
+    fn dynamic_case_calls_are_counted_but_not_listed() {
+        let src = "fn main() {\n    Harness::new(\"F\")\n        .case((1,), 1)\n        .cases(extra())\n        .example(\"x = 2\", \"2\")\n        .cases_file(\"f.cases\")?\n        .run();\n}\n";
+        let calls = harness_case_calls(src);
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].line, calls[1].line), (3, 5));
+
+        let dynamic = dynamic_case_call(src).unwrap();
+        assert_eq!(
+            (dynamic.method, dynamic.line, dynamic.after),
+            (".cases", 4, 1)
+        );
+
+        let commented = "fn main() {\n    Harness::new(\"F\")\n        // .cases(extra())\n        .case((1,), 1)\n        .run();\n}\n";
+        assert!(dynamic_case_call(commented).is_none());
+    }
+
+    #[test]
+    fn in_place_signature() {
+        let src = "struct Solution;\n\nimpl Solution {\n    pub fn rotate(nums: &mut Vec<i32>, k: i32) {\n        nums.rotate_right(k as usize);\n    }\n}\n";
+        let [signature] = solution_signatures(src).try_into().unwrap();
+
+        assert_eq!(signature.name, "rotate");
+        assert_eq!(signature.output, None);
+        assert!(signature.mutates());
+        assert_eq!(
+            signature.mutated(),
+            Some(&("nums".to_string(), "&mut Vec<i32>".to_string()))
+        );
+        assert_eq!(signature.to_string(), "rotate(nums: &mut Vec<i32>, k: i32)");
+    }
+
+    #[test]
+    fn tree_and_list_types() {
+        let src = "impl Solution {\n    pub fn max_depth(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {\n        0\n    }\n\n    pub fn reverse_list(head: Option<Box<ListNode>>) -> Option<Box<ListNode>> {\n        head\n    }\n\n    fn helper(&self) {}\n}\n";
+        let signatures: Vec<String> = solution_signatures(src)
+            .iter()
+            .map(Signature::to_string)
+            .collect();
+
+        assert_eq!(
+            signatures,
+            vec![
+                "max_depth(root: Option<Rc<RefCell<TreeNode>>>) -> i32",
+                "reverse_list(head: Option<Box<ListNode>>) -> Option<Box<ListNode>>",
+            ]
+        );
+        assert!(solution_signatures(src).iter().all(|s| !s.mutates()));
+    }
+
+    #[test]
+    fn approach_modules_are_skipped() {
+        let src = "struct Solution;\n\nimpl Solution {\n    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n        vec![]\n    }\n}\n\nmod brute_force {\n    pub struct Solution;\n\n    impl Solution {\n        pub fn two_sum_slow(nums: Vec<i32>, target: i32) -> Vec<i32> {\n            vec![]\n        }\n    }\n}\n";
+        let names: Vec<String> = solution_signatures(src)
+            .into_iter()
+            .map(|signature| signature.name)
+            .collect();
+
+        assert_eq!(names, vec!["two_sum"]);
+        assert!(solution_signatures("impl Solution {").is_empty());
+    }
+}
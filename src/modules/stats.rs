@@ -0,0 +1,258 @@
+use crate::modules::discovery::Problem;
+use crate::modules::history::{self, Event, EventKind, MockOutcome};
+use crate::modules::{metadata, source};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+
+/// Number of weeks shown in the activity heat map
+const HEATMAP_WEEKS: u64 = 12;
+
+/// Prints a progress report over `problems` and the recorded history
+pub fn print_report(problems: &[Problem]) {
+    let events = history::load();
+
+    println!("\n📊 Progress report\n");
+    print_breakdown(problems);
+    print_progress(problems, &events);
+    print_activity(&events);
+    print_loc(problems);
+    print_time_to_pass(&events);
+    print_mock_interviews();
+    print_most_failed(&events);
+}
+
+/// Counts per category and difficulty
+fn print_breakdown(problems: &[Problem]) {
+    // Keyed by directory so categories keep their numbered order
+    let mut categories: BTreeMap<(&str, &str), BTreeMap<String, usize>> = BTreeMap::new();
+    let mut difficulties: BTreeMap<String, usize> = BTreeMap::new();
+
+    for problem in problems {
+        let difficulty = metadata::load(problem).difficulty_label().to_string();
+        *categories
+            .entry((&problem.category, problem.category_label()))
+            .or_default()
+            .entry(difficulty.clone())
+            .or_default() += 1;
+        *difficulties.entry(difficulty).or_default() += 1;
+    }
+
+    println!("📁 By category:");
+    for ((_, category), counts) in &categories {
+        let total: usize = counts.values().sum();
+        let breakdown = counts
+            .iter()
+            .map(|(difficulty, count)| format!("{} {}", count, difficulty))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("   {:<20} {:>3}  ({})", category, total, breakdown);
+    }
+
+    println!("\n🎚️  By difficulty:");
+    for (difficulty, count) in &difficulties {
+        println!("   {:<20} {:>3}", difficulty, count);
+    }
+}
+
+/// Solved (has a passing run or practice) vs attempted (any activity)
+fn print_progress(problems: &[Problem], events: &[Event]) {
+    let known: HashSet<&str> = problems.iter().map(|p| p.bin_name.as_str()).collect();
+    let attempted: HashSet<&str> = events
+        .iter()
+        .map(|e| e.bin_name.as_str())
+        .filter(|name| known.contains(name))
+        .collect();
+    let solved: HashSet<String> = history::solved(events)
+        .into_iter()
+        .filter(|name| known.contains(name.as_str()))
+        .collect();
+
+    println!("\n✅ Progress:");
+    println!("   Solved:    {:>3} / {}", solved.len(), problems.len());
+    println!("   Attempted: {:>3} / {}", attempted.len(), problems.len());
+}
+
+/// Current streak and a weekday-by-week heat map of activity
+fn print_activity(events: &[Event]) {
+    let mut per_day: HashMap<u64, usize> = HashMap::new();
+    for event in events {
+        *per_day.entry(history::day_of(event.timestamp)).or_default() += 1;
+    }
+
+    let today = history::day_of(history::now());
+
+    // A streak survives until the end of today even if nothing was done yet
+    let mut day = if per_day.contains_key(&today) {
+        today
+    } else {
+        today.saturating_sub(1)
+    };
+    let mut streak = 0;
+    while per_day.contains_key(&day) {
+        streak += 1;
+        if day == 0 {
+            break;
+        }
+        day -= 1;
+    }
+
+    println!("\n🔥 Streak: {} day(s)", streak);
+    println!(
+        "\n🗓️  Activity (last {} weeks, ending {}):",
+        HEATMAP_WEEKS,
+        history::format_day(today)
+    );
+
+    // Columns are weeks starting on Sunday; 1970-01-01 was a Thursday
+    let weekday = |day: u64| (day + 4) % 7;
+    let first = today - weekday(today) - (HEATMAP_WEEKS - 1) * 7;
+    let labels = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+    for (row, label) in labels.iter().enumerate() {
+        let cells: String = (0..HEATMAP_WEEKS)
+            .map(|week| {
+                let day = first + week * 7 + row as u64;
+                if day > today {
+                    ' '
+                } else {
+                    heat_cell(per_day.get(&day).copied().unwrap_or(0))
+                }
+            })
+            .collect();
+        println!("   {} {}", label, cells);
+    }
+    println!("   Less · ░ ▒ ▓ █ More");
+}
+
+fn heat_cell(count: usize) -> char {
+    match count {
+        0 => '·',
+        1 => '░',
+        2..=3 => '▒',
+        4..=6 => '▓',
+        _ => '█',
+    }
+}
+
+/// Lines of code in each `impl Solution`
+fn print_loc(problems: &[Problem]) {
+    let mut rows: Vec<(usize, &Problem)> = problems
+        .iter()
+        .map(|problem| {
+            let loc = fs::read_to_string(&problem.path)
+                .map(|content| source::solution_loc(&content))
+                .unwrap_or(0);
+            (loc, problem)
+        })
+        .collect();
+    rows.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.bin_name.cmp(&b.1.bin_name)));
+
+    let total: usize = rows.iter().map(|(loc, _)| loc).sum();
+    println!("\n📏 Lines of code per solution ({} total):", total);
+    for (loc, problem) in rows {
+        println!("   {:<30} {:>4}", problem.name, loc);
+    }
+}
+
+/// Average time between scaffolding a practice attempt and passing it
+fn print_time_to_pass(events: &[Event]) {
+    let mut started: HashMap<&str, u64> = HashMap::new();
+    let mut durations = Vec::new();
+
+    for event in events {
+        match event.kind {
+            EventKind::Start => {
+                started.insert(&event.bin_name, event.timestamp);
+            }
+            EventKind::Practice if event.passed => {
+                if let Some(start) = started.remove(event.bin_name.as_str()) {
+                    durations.push(event.timestamp.saturating_sub(start));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    print!("\n⏱️  Average time-to-pass: ");
+    if durations.is_empty() {
+        println!("no passing practice attempts yet");
+    } else {
+        let average = durations.iter().sum::<u64>() / durations.len() as u64;
+        println!(
+            "{} ({} attempt(s))",
+            format_duration(average),
+            durations.len()
+        );
+    }
+}
+
+/// Sessions of `mock`, with the score of the latest and best one
+fn print_mock_interviews() {
+    let results = history::load_mock();
+
+    // Scores per session, in the order they were recorded
+    let mut sessions: Vec<(u64, usize, usize)> = Vec::new();
+    for result in &results {
+        if sessions
+            .last()
+            .is_none_or(|(session, _, _)| *session != result.session)
+        {
+            sessions.push((result.session, 0, 0));
+        }
+        if let Some((_, solved, total)) = sessions.last_mut() {
+            *total += 1;
+            if result.outcome == MockOutcome::Solved {
+                *solved += 1;
+            }
+        }
+    }
+
+    print!("\n🎤 Mock interviews: ");
+    let Some(&(last, solved, total)) = sessions.last() else {
+        println!("none yet");
+        return;
+    };
+    println!("{} session(s)", sessions.len());
+    println!(
+        "   Latest: {}/{} solved on {}",
+        solved,
+        total,
+        history::format_day(history::day_of(last))
+    );
+    if let Some((_, solved, total)) = sessions
+        .iter()
+        .max_by(|a, b| (a.1 * b.2).cmp(&(b.1 * a.2)).then(b.0.cmp(&a.0)))
+    {
+        println!("   Best:   {}/{} solved", solved, total);
+    }
+}
+
+/// Problems with the most failed runs and practice checks
+fn print_most_failed(events: &[Event]) {
+    let mut failures: HashMap<&str, usize> = HashMap::new();
+    for event in events.iter().filter(|e| !e.passed) {
+        *failures.entry(&event.bin_name).or_default() += 1;
+    }
+
+    let mut rows: Vec<(&str, usize)> = failures.into_iter().collect();
+    rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    println!("\n💥 Most failed:");
+    if rows.is_empty() {
+        println!("   No failures recorded 🎉");
+    }
+    for (name, count) in rows.into_iter().take(5) {
+        println!("   {:<30} {:>3}", name, count);
+    }
+    println!();
+}
+
+/// Formats seconds as e.g. "1h 05m" or "12m 30s"
+pub fn format_duration(seconds: u64) -> String {
+    let (hours, minutes, secs) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, secs)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::modules::leetcode::{self, Value};
+use crate::modules::provider::ProblemSpec;
+use crate::modules::source::{self, Signature};
+use crate::modules::{discovery, updater};
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// A new solution file for `spec`: its header, the starter code with
+/// `todo!()` bodies under the same prelude as the practice workspace, and
+/// a harness that runs the examples. Examples
+/// become typed `.case(..)` calls when their values fit the method's
+/// types, and stay `.example(..)` in LeetCode notation otherwise.
+pub fn render(spec: &ProblemSpec) -> Result<String, String> {
+    let signature = source::solution_signatures(&spec.starter)
+        .into_iter()
+        .next()
+        .ok_or("❌ The starter code has no `impl Solution` method")?;
+
+    let mut out = String::new();
+    out.push_str(&format!("//! title: {}\n", spec.title));
+    for (key, value) in [
+        ("link", &spec.link),
+        ("difficulty", &spec.difficulty),
+        ("description", &spec.description),
+    ] {
+        if let Some(value) = value {
+            out.push_str(&format!("//! {}: {}\n", key, value));
+        }
+    }
+
+    let cases: Vec<String> = spec
+        .examples
+        .iter()
+        .map(|(input, output)| {
+            typed_case(&signature, input, output)
+                .unwrap_or_else(|| format!(".example({:?}, {:?})", input, output))
+        })
+        .collect();
+
+    out.push_str(&format!("\n{}\n\n", source::BLANK_PRELUDE));
+    for import in imports(&spec.starter, &cases) {
+        out.push_str(&format!("use {};\n", import));
+    }
+    out.push('\n');
+    if !spec.starter.contains("struct Solution") {
+        out.push_str("struct Solution;\n\n");
+    }
+    out.push_str(&source::blank_bodies(spec.starter.trim()));
+
+    out.push_str("\n\nfn main() {\n");
+    out.push_str(&format!("    Harness::new({:?})\n", spec.title));
+    out.push_str(&format!(
+        "        .approach(\"solution\", {})\n",
+        approach(&signature)
+    ));
+    for case in cases {
+        out.push_str(&format!("        {}\n", case));
+    }
+    out.push_str("        .run();\n}\n");
+    Ok(out)
+}
+
+/// `use` paths for the harness and the LeetCode types the code mentions
+fn imports(starter: &str, cases: &[String]) -> Vec<&'static str> {
+    let uses_list = cases.iter().any(|case| case.contains("list::"));
+    let uses_tree = cases.iter().any(|case| case.contains("tree::"));
+
+    let mut imports = vec!["dsa::modules::harness::Harness"];
+    match (starter.contains("ListNode"), uses_list) {
+        (true, true) => imports.push("dsa::modules::list::{self, ListNode}"),
+        (true, false) => imports.push("dsa::modules::list::ListNode"),
+        _ => {}
+    }
+    match (starter.contains("TreeNode"), uses_tree) {
+        (true, true) => imports.push("dsa::modules::tree::{self, TreeNode}"),
+        (true, false) => imports.push("dsa::modules::tree::TreeNode"),
+        _ => {}
+    }
+    if starter.contains("RefCell") {
+        imports.push("std::cell::RefCell");
+    }
+    if starter.contains("Rc<") {
+        imports.push("std::rc::Rc");
+    }
+    imports
+}
+
+/// `.case((args,), expected)` for an example, or `None` when a value
+/// doesn't fit its parameter's type
+fn typed_case(signature: &Signature, input: &str, output: &str) -> Option<String> {
+    let args = leetcode::parse_args(input).ok()?;
+    if args.len() != signature.params.len() {
+        return None;
+    }
+    let args = args
+        .iter()
+        .zip(&signature.params)
+        .map(|((_, value), (_, ty))| rust_literal(value, ty))
+        .collect::<Option<Vec<_>>>()?;
+
+    // Methods that work in place are checked on the argument they mutate
+    let output_ty = signature
+        .output
+        .as_deref()
+        .or_else(|| signature.mutated().map(|(_, ty)| ty.as_str()))?;
+    let [(_, expected)] = leetcode::parse_args(output).ok()?.try_into().ok()?;
+    let expected = rust_literal(&expected, output_ty)?;
+
+    let input = match args.as_slice() {
+        [single] => format!("({},)", single),
+        _ => format!("({})", args.join(", ")),
+    };
+    Some(format!(".case({}, {})", input, expected))
+}
+
+/// Rust expression of type `ty` for a value in LeetCode notation
+fn rust_literal(value: &Value, ty: &str) -> Option<String> {
+    let ty = ty
+        .trim_start_matches("&mut ")
+        .trim_start_matches('&')
+        .trim();
+
+    if let Some(inner) = generic_arg(ty, "Vec") {
+        let Value::List(items) = value else {
+            return None;
+        };
+        let items = items
+            .iter()
+            .map(|item| rust_literal(item, inner))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(format!("vec![{}]", items.join(", ")));
+    }
+
+    match (ty, value) {
+        ("Option<Box<ListNode>>", Value::List(items)) => {
+            let values = items
+                .iter()
+                .map(|item| rust_literal(item, "i32"))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("list::from_vec(&[{}])", values.join(", ")))
+        }
+        ("Option<Rc<RefCell<TreeNode>>>", Value::List(items)) => {
+            let values = items
+                .iter()
+                .map(|item| rust_literal(item, "Option<i32>"))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("tree::from_level_order(&[{}])", values.join(", ")))
+        }
+        (ty, Value::Null) if ty.starts_with("Option<") => Some("None".to_string()),
+        (ty, value) if ty.starts_with("Option<") => {
+            let inner = generic_arg(ty, "Option")?;
+            Some(format!("Some({})", rust_literal(value, inner)?))
+        }
+        (
+            "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize",
+            Value::Int(n),
+        ) => (!n.is_negative() || ty.starts_with('i')).then(|| n.to_string()),
+        ("f32" | "f64", Value::Int(n)) => Some(format!("{:?}", *n as f64)),
+        ("f32" | "f64", Value::Float(x)) => Some(format!("{:?}", x)),
+        ("bool", Value::Bool(b)) => Some(b.to_string()),
+        ("String" | "str", Value::Str(s)) => Some(format!("{:?}.to_string()", s)),
+        ("char", Value::Str(s)) if s.chars().count() == 1 => {
+            Some(format!("{:?}", s.chars().next()?))
+        }
+        _ => None,
+    }
+}
+
+/// `T` from `Name<T>`
+fn generic_arg<'a>(ty: &'a str, name: &str) -> Option<&'a str> {
+    ty.strip_prefix(name)?
+        .trim()
+        .strip_prefix('<')?
+        .strip_suffix('>')
+        .map(str::trim)
+}
+
+/// Writes the solution for `spec` into `category` and registers it in
+/// Cargo.toml. Returns the new file's path.
+pub fn create(spec: &ProblemSpec, category: &str) -> Result<String, String> {
+    if !category.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(format!(
+            "❌ '{}' is not a category\n💡 Categories are numbered directories like 01-arrays",
+            category
+        ));
+    }
+
+    let path = format!("{}/{}.rs", category, spec.bin_name());
+    if Path::new(&path).exists() {
+        return Err(format!("❌ {} already exists", path));
+    }
+
+    let content = render(spec)?;
+    fs::create_dir_all(category).map_err(|e| format!("❌ Failed to create {}: {}", category, e))?;
+    fs::write(&path, content).map_err(|e| format!("❌ Failed to write {}: {}", path, e))?;
+    // Best effort: the generated chains can run past the line width
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(&path)
+        .stderr(Stdio::null())
+        .status();
+
+    updater::update_cargo_toml(&discovery::discover_problems())?;
+    Ok(path)
+}
+
+/// Harness closure calling the method with the input tuple. Methods that
+/// mutate an argument in place return it instead of `()`.
+fn approach(signature: &Signature) -> String {
+    let mutated = signature.mutated().map(|(name, _)| name.as_str());
+
+    let mut bindings = Vec::new();
+    let mut args = Vec::new();
+    for (name, ty) in &signature.params {
+        if ty.starts_with("&mut") {
+            bindings.push(format!("mut {}", name));
+            args.push(format!("&mut {}", name));
+        } else if ty.starts_with('&') {
+            bindings.push(name.clone());
+            args.push(format!("&{}", name));
+        } else {
+            bindings.push(name.clone());
+            args.push(name.clone());
+        }
+    }
+
+    let pattern = match bindings.as_slice() {
+        [single] => format!("({},)", single),
+        _ => format!("({})", bindings.join(", ")),
+    };
+    let call = format!("Solution::{}({})", signature.name, args.join(", "));
+
+    match mutated {
+        Some(name) if signature.output.is_none() => format!(
+            "|{}| {{\n            {};\n            {}\n        }}",
+            pattern, call, name
+        ),
+        _ => format!("|{}| {}", pattern, call),
+    }
+}
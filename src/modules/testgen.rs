@@ -0,0 +1,160 @@
+use crate::modules::discovery::Problem;
+use crate::modules::{cases, source};
+use std::fs;
+use std::path::Path;
+
+/// Where cargo looks for integration tests
+const TESTS_DIR: &str = "tests";
+
+/// First line of every generated file; files without it are left alone
+const GENERATED_HEADER: &str = "// Auto-generated by `cargo run update --tests`, do not edit.";
+
+/// Longest case description kept in a test name
+const NAME_LIMIT: usize = 40;
+
+/// Writes `tests/<bin>.rs` with one `#[test]` per harness case of each
+/// problem, and removes generated files whose problem is gone. Returns
+/// how many test functions were written.
+pub fn write_tests(problems: &[Problem]) -> Result<usize, String> {
+    fs::create_dir_all(TESTS_DIR)
+        .map_err(|e| format!("❌ Failed to create {}: {}", TESTS_DIR, e))?;
+
+    let mut written = Vec::new();
+    let mut total = 0;
+
+    for problem in problems {
+        let src = fs::read_to_string(&problem.path)
+            .map_err(|e| format!("❌ Failed to read {}: {}", problem.path, e))?;
+        if !src.contains("Harness::new") {
+            continue;
+        }
+        if let Some(dynamic) = source::dynamic_case_call(&src) {
+            // Its case indexes are only known when the harness runs
+            println!(
+                "⚠️  Skipping {}: {}(..) on line {} adds cases without a fixed index",
+                problem.path, dynamic.method, dynamic.line
+            );
+            continue;
+        }
+
+        let inputs = case_inputs(problem, &src);
+        if inputs.is_empty() {
+            continue;
+        }
+
+        let path = format!("{}/{}.rs", TESTS_DIR, problem.bin_name);
+        fs::write(&path, render(problem, &inputs))
+            .map_err(|e| format!("❌ Failed to write {}: {}", path, e))?;
+        written.push(path);
+        total += inputs.len();
+    }
+
+    remove_stale(&written)?;
+    println!(
+        "🧪 Generated {} test(s) in {} file(s) under {}/",
+        total,
+        written.len(),
+        TESTS_DIR
+    );
+    Ok(total)
+}
+
+/// Case inputs in the order the harness runs them: calls in `fn main`,
+/// then the `.cases` file
+fn case_inputs(problem: &Problem, src: &str) -> Vec<String> {
+    let mut inputs: Vec<String> = source::harness_case_calls(src)
+        .into_iter()
+        .map(|case| case.input)
+        .collect();
+
+    let sidecar = problem.sidecar_path(cases::EXTENSION);
+    if let Ok(text) = fs::read_to_string(&sidecar)
+        && let Ok(stored) = cases::parse_file(&text)
+    {
+        inputs.extend(stored.into_iter().map(|case| case.input));
+    }
+    inputs
+}
+
+fn render(problem: &Problem, inputs: &[String]) -> String {
+    let mut out = format!(
+        "{}\n// Cases of {}; run with: cargo test --test {}\n\nuse dsa::problems::assert_case;\n",
+        GENERATED_HEADER, problem.path, problem.bin_name
+    );
+
+    let width = inputs.len().to_string().len();
+    for (i, input) in inputs.iter().enumerate() {
+        out.push_str(&format!(
+            "\n#[test]\nfn {}() {{\n    assert_case({:?}, {});\n}}\n",
+            test_name(i + 1, width, input),
+            problem.bin_name,
+            i
+        ));
+    }
+    out
+}
+
+/// `case_03_nums_neg1_0_1`: numbered so names stay unique and sorted,
+/// described by the input so failures are recognizable
+fn test_name(number: usize, width: usize, input: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for c in input.replace("vec!", "").replace("to_string()", "").chars() {
+        if c.is_ascii_alphanumeric() {
+            current.push(c.to_ascii_lowercase());
+            continue;
+        }
+        if !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        if c == '-' {
+            current.push_str("neg");
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let mut name = format!("case_{:0width$}", number, width = width);
+    let mut described = 0;
+    for word in words.iter().filter(|w| *w != "neg") {
+        if described + word.len() + 1 > NAME_LIMIT {
+            break;
+        }
+        name.push('_');
+        name.push_str(word);
+        described += word.len() + 1;
+    }
+    if described == 0 {
+        name.push_str("_empty");
+    }
+    name
+}
+
+/// Deletes generated test files that were not written this time
+fn remove_stale(written: &[String]) -> Result<(), String> {
+    let Ok(entries) = fs::read_dir(TESTS_DIR) else {
+        return Ok(());
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let path_str = format!(
+            "{}/{}",
+            TESTS_DIR,
+            path.file_name().and_then(|s| s.to_str()).unwrap_or("")
+        );
+        if written.contains(&path_str) || !is_generated(&path) {
+            continue;
+        }
+        fs::remove_file(&path)
+            .map_err(|e| format!("❌ Failed to remove {}: {}", path.display(), e))?;
+        println!("🗑️  Removed stale {}", path_str);
+    }
+    Ok(())
+}
+
+fn is_generated(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|text| text.starts_with(GENERATED_HEADER))
+}
@@ -0,0 +1,263 @@
+use crate::modules::fuzz::Shrink;
+use crate::modules::leetcode::{FromLeetCode, ToLeetCode, Value};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+/// LeetCode's binary tree node. Trees are written in level order with
+/// `null` for missing children, e.g. `[1,null,2,3]`, and `[]` is `None`.
+///
+/// Cloning an `Rc` shares the nodes, so a harness whose solutions modify
+/// the tree should take the level-order vector as input and build a fresh
+/// tree per run with [`from_level_order`].
+#[derive(PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<Rc<RefCell<TreeNode>>>,
+    pub right: Option<Rc<RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// The type LeetCode's tree signatures use
+pub type Tree = Option<Rc<RefCell<TreeNode>>>;
+
+fn node(val: i32) -> Rc<RefCell<TreeNode>> {
+    Rc::new(RefCell::new(TreeNode::new(val)))
+}
+
+/// Builds a tree from LeetCode's level-order serialization
+pub fn from_level_order(values: &[Option<i32>]) -> Tree {
+    let mut values = values.iter();
+    let root = node((*values.next()?)?);
+    let mut queue = VecDeque::from([Rc::clone(&root)]);
+
+    while let Some(parent) = queue.pop_front() {
+        for is_left in [true, false] {
+            let Some(value) = values.next() else {
+                return Some(root);
+            };
+            if let Some(val) = *value {
+                let child = node(val);
+                queue.push_back(Rc::clone(&child));
+                let mut parent = parent.borrow_mut();
+                if is_left {
+                    parent.left = Some(child);
+                } else {
+                    parent.right = Some(child);
+                }
+            }
+        }
+    }
+    Some(root)
+}
+
+/// Serializes a tree in level order, without trailing `null`s
+pub fn to_level_order(root: &Tree) -> Vec<Option<i32>> {
+    let mut values = Vec::new();
+    let mut queue = VecDeque::from([root.clone()]);
+
+    while let Some(current) = queue.pop_front() {
+        match current {
+            Some(current) => {
+                let current = current.borrow();
+                values.push(Some(current.val));
+                queue.push_back(current.left.clone());
+                queue.push_back(current.right.clone());
+            }
+            None => values.push(None),
+        }
+    }
+
+    while values.last() == Some(&None) {
+        values.pop();
+    }
+    values
+}
+
+/// Copies every node, so the result can be modified independently
+pub fn deep_clone(root: &Tree) -> Tree {
+    from_level_order(&to_level_order(root))
+}
+
+/// Draws a tree with `/` and `\` connectors:
+///
+/// ```text
+///   1
+///  / \
+/// 2   3
+/// ```
+pub fn draw(root: &Tree) -> String {
+    match root {
+        Some(root) => {
+            let (lines, _, _) = draw_lines(&root.borrow());
+            lines
+                .iter()
+                .map(|line| line.trim_end())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        None => "(empty)".to_string(),
+    }
+}
+
+/// Renders a subtree as equal-width lines; returns the lines, their width
+/// and the column of the root label's middle
+fn draw_lines(node: &TreeNode) -> (Vec<String>, usize, usize) {
+    let label = node.val.to_string();
+    let u = label.len();
+    let sub = |child: &Tree| child.as_ref().map(|c| draw_lines(&c.borrow()));
+
+    match (sub(&node.left), sub(&node.right)) {
+        (None, None) => (vec![label], u, u / 2),
+        (Some((lines, n, x)), None) => {
+            let mut out = vec![
+                format!("{}{}{}", " ".repeat(x + 1), "_".repeat(n - x - 1), label),
+                format!("{}/{}", " ".repeat(x), " ".repeat(n - x - 1 + u)),
+            ];
+            out.extend(lines.into_iter().map(|line| line + &" ".repeat(u)));
+            (out, n + u, n + u / 2)
+        }
+        (None, Some((lines, n, x))) => {
+            let mut out = vec![
+                format!("{}{}{}", label, "_".repeat(x), " ".repeat(n - x)),
+                format!("{}\\{}", " ".repeat(u + x), " ".repeat(n - x - 1)),
+            ];
+            out.extend(lines.into_iter().map(|line| " ".repeat(u) + &line));
+            (out, n + u, u / 2)
+        }
+        (Some((mut left, n, x)), Some((mut right, m, y))) => {
+            let mut out = vec![
+                format!(
+                    "{}{}{}{}{}",
+                    " ".repeat(x + 1),
+                    "_".repeat(n - x - 1),
+                    label,
+                    "_".repeat(y),
+                    " ".repeat(m - y)
+                ),
+                format!(
+                    "{}/{}\\{}",
+                    " ".repeat(x),
+                    " ".repeat(n - x - 1 + u + y),
+                    " ".repeat(m - y - 1)
+                ),
+            ];
+            let height = left.len().max(right.len());
+            left.resize(height, " ".repeat(n));
+            right.resize(height, " ".repeat(m));
+            out.extend(
+                left.iter()
+                    .zip(&right)
+                    .map(|(a, b)| format!("{}{}{}", a, " ".repeat(u), b)),
+            );
+            (out, n + m + u, n + u / 2)
+        }
+    }
+}
+
+/// Shows the level-order serialization, e.g. `[1, null, 2]`
+impl fmt::Debug for TreeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let root = Some(Rc::new(RefCell::new(TreeNode {
+            val: self.val,
+            left: self.left.clone(),
+            right: self.right.clone(),
+        })));
+        let values: Vec<String> = to_level_order(&root)
+            .iter()
+            .map(|v| v.map_or("null".to_string(), |v| v.to_string()))
+            .collect();
+        write!(f, "[{}]", values.join(", "))
+    }
+}
+
+impl FromLeetCode for Rc<RefCell<TreeNode>> {
+    const EMPTY_IS_NONE: bool = true;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        let values = Vec::<Option<i32>>::from_value(value)?;
+        from_level_order(&values).ok_or_else(|| "expected a non-empty tree".to_string())
+    }
+}
+
+impl ToLeetCode for Rc<RefCell<TreeNode>> {
+    const EMPTY_IS_NONE: bool = true;
+
+    fn to_value(&self) -> Value {
+        to_level_order(&Some(Rc::clone(self))).to_value()
+    }
+}
+
+impl Shrink for Rc<RefCell<TreeNode>> {
+    fn shrink(&self) -> Vec<Self> {
+        to_level_order(&Some(Rc::clone(self)))
+            .shrink()
+            .iter()
+            .filter_map(|values| from_level_order(values))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_order_round_trips_with_null_holes() {
+        for values in [
+            vec![Some(1), None, Some(2), Some(3)],
+            vec![Some(1), Some(2), Some(3), None, Some(4), None, Some(5)],
+            vec![Some(5), Some(4), None, Some(3), None, Some(2)],
+            vec![Some(1)],
+        ] {
+            assert_eq!(to_level_order(&from_level_order(&values)), values);
+        }
+    }
+
+    #[test]
+    fn trailing_nulls_are_trimmed() {
+        let tree = from_level_order(&[Some(1), Some(2), None, None, None]);
+        assert_eq!(to_level_order(&tree), vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn empty_and_null_roots_are_none() {
+        assert_eq!(from_level_order(&[]), None);
+        assert_eq!(from_level_order(&[None]), None);
+        assert!(to_level_order(&None).is_empty());
+    }
+
+    #[test]
+    fn deep_clone_does_not_share_nodes() {
+        let tree = from_level_order(&[Some(1), Some(2)]);
+        let copy = deep_clone(&tree);
+        tree.as_ref().unwrap().borrow_mut().val = 9;
+        assert_eq!(to_level_order(&copy), vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn draw_places_children_under_connectors() {
+        assert_eq!(draw(&None), "(empty)");
+        assert_eq!(draw(&from_level_order(&[Some(7)])), "7");
+        assert_eq!(
+            draw(&from_level_order(&[Some(1), Some(2), Some(3)])),
+            " 1\n/ \\\n2 3"
+        );
+        assert_eq!(
+            draw(&from_level_order(&[Some(1), None, Some(2)])),
+            "1\n \\\n 2"
+        );
+        assert_eq!(draw(&from_level_order(&[Some(10), Some(2)])), " 10\n/\n2");
+    }
+}
@@ -1,7 +1,11 @@
 use crate::modules::discovery::Problem;
+use crate::modules::drift::{self, Drift};
+use crate::modules::practice::{PRACTICE_BIN, PRACTICE_PATH};
 use std::fs;
 use std::io::Write;
 
+const BIN_HEADER: &str = "# Auto-generated binary entries";
+
 /// Updates Cargo.toml with all discovered problems
 pub fn update_cargo_toml(problems: &[Problem]) -> Result<(), String> {
     if problems.is_empty() {
@@ -20,6 +24,10 @@ pub fn update_cargo_toml(problems: &[Problem]) -> Result<(), String> {
     let mut skip_bin_section = false;
 
     for line in lines {
+        if line.trim() == BIN_HEADER {
+            continue;
+        }
+
         if line.trim().starts_with("[[bin]]") {
             skip_bin_section = true;
             continue;
@@ -45,13 +53,13 @@ pub fn update_cargo_toml(problems: &[Problem]) -> Result<(), String> {
 
     // Build new Cargo.toml content
     let mut new_content = new_lines.join("\n");
-    new_content.push_str("\n\n# Auto-generated binary entries\n");
+    new_content.push_str(&format!("\n\n{}\n", BIN_HEADER));
+
+    // The practice workspace is not a numbered category, keep it first
+    push_bin(&mut new_content, PRACTICE_BIN, PRACTICE_PATH);
 
     for problem in problems {
-        new_content.push_str(&format!("[[bin]]\n"));
-        new_content.push_str(&format!("name = \"{}\"\n", problem.bin_name));
-        new_content.push_str(&format!("path = \"{}\"\n", problem.path));
-        new_content.push_str("\n");
+        push_bin(&mut new_content, &problem.bin_name, &problem.path);
     }
 
     // Write back to Cargo.toml
@@ -64,3 +72,18 @@ pub fn update_cargo_toml(problems: &[Problem]) -> Result<(), String> {
     println!("✅ Successfully updated Cargo.toml!");
     Ok(())
 }
+
+/// Compares Cargo.toml with the discovered problems without writing it
+pub fn check_cargo_toml(problems: &[Problem]) -> Result<Drift, String> {
+    let cargo_content = fs::read_to_string("Cargo.toml")
+        .map_err(|e| format!("❌ Failed to read Cargo.toml: {}", e))?;
+    let files: Vec<String> = problems.iter().map(|p| p.path.clone()).collect();
+    Ok(drift::compare(&cargo_content, &files))
+}
+
+fn push_bin(content: &mut String, name: &str, path: &str) {
+    content.push_str("[[bin]]\n");
+    content.push_str(&format!("name = \"{}\"\n", name));
+    content.push_str(&format!("path = \"{}\"\n", path));
+    content.push('\n');
+}
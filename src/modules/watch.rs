@@ -0,0 +1,222 @@
+use crate::modules::discovery::{self, Problem};
+use crate::modules::harness::format_elapsed;
+use crate::modules::runner::{self, BatchResult};
+use crate::modules::{cases, diagnostics, updater};
+use notify::{Event, RecursiveMode, Watcher};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Editors save in bursts (temp file, rename, chmod); wait this long after
+/// the last event before re-running
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Last known state of a watched problem
+enum Status {
+    Passed(Duration),
+    Failed(Duration),
+    BuildFailed,
+}
+
+/// What to watch: one problem by bin name, or every problem in the
+/// categories matching `filter`
+pub struct Selection {
+    filter: String,
+    single: bool,
+}
+
+impl Selection {
+    /// Treats `name` as a bin name when one matches, else as a category
+    pub fn new(name: &str) -> Self {
+        let single = discovery::discover_problems()
+            .iter()
+            .any(|p| p.bin_name == name);
+        Selection {
+            filter: name.to_string(),
+            single,
+        }
+    }
+
+    fn problems(&self) -> Vec<Problem> {
+        if self.single {
+            discovery::discover_problems()
+                .into_iter()
+                .filter(|p| p.bin_name == self.filter)
+                .collect()
+        } else {
+            discovery::list_by_category(&self.filter)
+        }
+    }
+}
+
+/// Runs the selection, then re-runs affected problems whenever a solution
+/// or its `.cases` file changes, until interrupted
+pub fn watch(selection: &Selection) -> Result<(), String> {
+    let mut problems = selection.problems();
+    if problems.is_empty() {
+        return Err(format!(
+            "❌ No problem or category matches '{}'",
+            selection.filter
+        ));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            let _ = sender.send(event);
+        }
+    })
+    .map_err(|e| format!("❌ Failed to start file watcher: {}", e))?;
+
+    let directories: BTreeSet<String> = problems.iter().map(|p| p.category.clone()).collect();
+    for directory in &directories {
+        watcher
+            .watch(Path::new(directory), RecursiveMode::NonRecursive)
+            .map_err(|e| format!("❌ Failed to watch {}: {}", directory, e))?;
+    }
+
+    let mut statuses = HashMap::new();
+    let mut results = rerun(&problems, &mut statuses);
+    redraw(selection, &problems, &statuses, &results, None);
+
+    while let Ok(first) = receiver.recv() {
+        let mut changed = relevant_paths(&first);
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            changed.extend(relevant_paths(&event));
+        }
+        if changed.is_empty() {
+            continue;
+        }
+
+        // New or deleted solution files need their [[bin]] entries synced,
+        // checked against every problem rather than only the selected ones
+        let all = discovery::discover_problems();
+        let structure_changed = changed
+            .iter()
+            .any(|path| path.extension().is_some_and(|e| e == "rs"))
+            && updater::check_cargo_toml(&all).is_ok_and(|drift| !drift.is_empty());
+        if structure_changed {
+            // Keep watching; the update is retried on the next save
+            if let Err(e) = updater::update_cargo_toml(&all) {
+                eprintln!("{}", e);
+            }
+            problems = selection.problems();
+        }
+
+        let affected: Vec<Problem> = problems
+            .iter()
+            .filter(|p| changed.iter().any(|path| belongs_to(path, p)))
+            .cloned()
+            .collect();
+        if affected.is_empty() && !structure_changed {
+            continue;
+        }
+
+        results = if affected.is_empty() {
+            Vec::new()
+        } else {
+            rerun(&affected, &mut statuses)
+        };
+        let trigger = changed
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        redraw(selection, &problems, &statuses, &results, Some(&trigger));
+    }
+    Ok(())
+}
+
+/// Solution and `.cases` files touched by `event`, relative to the
+/// working directory like [`Problem::path`]
+fn relevant_paths(event: &Event) -> BTreeSet<PathBuf> {
+    if !(event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove()) {
+        return BTreeSet::new();
+    }
+
+    let cwd = std::env::current_dir().unwrap_or_default();
+    event
+        .paths
+        .iter()
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|e| e == "rs" || e == cases::EXTENSION)
+        })
+        .map(|path| path.strip_prefix(&cwd).unwrap_or(path).to_path_buf())
+        .collect()
+}
+
+fn belongs_to(path: &Path, problem: &Problem) -> bool {
+    path.parent()
+        .is_some_and(|dir| dir == Path::new(&problem.category))
+        && path
+            .file_stem()
+            .is_some_and(|stem| stem == problem.bin_name.as_str())
+}
+
+/// Builds and runs `problems`, updating their statuses. Returns the
+/// output worth showing: failures and compile errors.
+fn rerun(problems: &[Problem], statuses: &mut HashMap<String, Status>) -> Vec<(String, String)> {
+    let bins: Vec<String> = problems.iter().map(|p| p.bin_name.clone()).collect();
+    let build = match runner::build_bins(&bins, false) {
+        Ok(build) => build,
+        Err(e) => return vec![("cargo".to_string(), e)],
+    };
+    let outcomes: Vec<BatchResult> = runner::run_artifacts(&build.artifacts, &[]);
+
+    let mut shown = Vec::new();
+    for problem in problems {
+        match outcomes.iter().find(|r| r.bin_name == problem.bin_name) {
+            Some(result) if result.passed => {
+                statuses.insert(problem.bin_name.clone(), Status::Passed(result.elapsed));
+            }
+            Some(result) => {
+                statuses.insert(problem.bin_name.clone(), Status::Failed(result.elapsed));
+                shown.push((problem.bin_name.clone(), result.output.clone()));
+            }
+            None => {
+                statuses.insert(problem.bin_name.clone(), Status::BuildFailed);
+                let mut messages = build.diagnostics_for(&problem.bin_name);
+                messages.extend(build.diagnostics_for("dsa"));
+                shown.push((problem.bin_name.clone(), diagnostics::render(&messages)));
+            }
+        }
+    }
+    shown
+}
+
+fn redraw(
+    selection: &Selection,
+    problems: &[Problem],
+    statuses: &HashMap<String, Status>,
+    output: &[(String, String)],
+    trigger: Option<&str>,
+) {
+    // Clear the screen and move the cursor home
+    print!("\x1B[2J\x1B[H");
+    println!(
+        "👀 Watching {} ({} problem(s)), Ctrl-C to stop",
+        selection.filter,
+        problems.len()
+    );
+    if let Some(trigger) = trigger {
+        println!("🔁 Changed: {}", trigger);
+    }
+    println!();
+
+    for problem in problems {
+        let (mark, detail) = match statuses.get(&problem.bin_name) {
+            Some(Status::Passed(elapsed)) => ("✅", format_elapsed(*elapsed)),
+            Some(Status::Failed(elapsed)) => ("❌", format_elapsed(*elapsed)),
+            Some(Status::BuildFailed) => ("🔨", "build failed".to_string()),
+            None => ("⏳", "not run".to_string()),
+        };
+        println!("  {} {:<30} {:>12}", mark, problem.name, detail);
+    }
+
+    for (bin_name, text) in output {
+        println!("\n── {} {}", bin_name, "─".repeat(40));
+        println!("{}", text.trim_end());
+    }
+}
@@ -0,0 +1,59 @@
+//! Every solution compiled into the library, generated by `build.rs`.
+//! Only built with the `problems` feature, which tests always enable through
+//! the crate's dev-dependency on itself.
+
+use crate::modules::bench::BenchConfig;
+use crate::modules::harness;
+
+/// Looks up a solution's `main` by bin name
+pub fn find(bin_name: &str) -> Option<fn()> {
+    ALL.iter()
+        .find(|(name, _)| *name == bin_name)
+        .map(|&(_, main)| main)
+}
+
+/// Runs a solution's harness in-process and fails on any failed case.
+/// Solutions that don't use a harness only have to run without panicking.
+pub fn assert_passes(bin_name: &str) {
+    let summary = harness::capture(bin_name, main_of(bin_name));
+    assert_summary(summary);
+}
+
+/// Runs one case of a solution's harness, by index, in-process
+pub fn assert_case(bin_name: &str, index: usize) {
+    let summary = harness::capture_case(bin_name, index, main_of(bin_name));
+    assert!(summary.is_some(), "{} doesn't run a harness", bin_name);
+    assert_summary(summary);
+}
+
+/// Benchmarks a solution's approaches in-process, without building and
+/// running its bin. Fails when there is no such solution or it doesn't
+/// run a harness.
+pub fn bench(bin_name: &str, config: &BenchConfig) -> Result<(), String> {
+    let main = find(bin_name).ok_or_else(|| format!("❌ No solution named '{}'", bin_name))?;
+    harness::capture_bench(bin_name, config, main)
+        .map(|_| ())
+        .ok_or_else(|| format!("❌ {} doesn't run a harness", bin_name))
+}
+
+fn main_of(bin_name: &str) -> fn() {
+    find(bin_name).unwrap_or_else(|| panic!("no solution named '{}'", bin_name))
+}
+
+fn assert_summary(summary: Option<harness::Summary>) {
+    let Some(summary) = summary else {
+        return;
+    };
+    for failure in &summary.failures {
+        println!("{}\n", failure);
+    }
+    assert!(
+        summary.failures.is_empty(),
+        "{}: {} failure(s) over {} case(s)",
+        summary.title,
+        summary.failures.len(),
+        summary.cases
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/problems.rs"));